@@ -0,0 +1,29 @@
+//! Options that make the game more comfortable to play for more people.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::settings::{RegisterSetting as _, Setting};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_setting::<AccessibilitySettings>();
+}
+
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct AccessibilitySettings {
+    /// Disable or tone down effects that can cause motion sickness, such as
+    /// flashes, squash and stretch, parallax, afterimage trails, and screen
+    /// transitions.
+    /// These are purely cosmetic, so gameplay is unaffected.
+    pub reduced_motion: bool,
+}
+
+impl Setting for AccessibilitySettings {
+    const KEY: &'static str = "accessibility";
+}
+
+/// A run condition that is `true` if [`AccessibilitySettings::reduced_motion`] is enabled.
+pub fn reduced_motion(settings: Res<AccessibilitySettings>) -> bool {
+    settings.reduced_motion
+}
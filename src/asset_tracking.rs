@@ -1,8 +1,12 @@
 //! A high-level way to load collections of asset handles as resources.
 
-use std::collections::VecDeque;
+use std::{collections::VecDeque, marker::PhantomData};
 
-use bevy::prelude::*;
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt as _, LoadContext},
+    prelude::*,
+};
+use serde::de::DeserializeOwned;
 
 pub(super) fn plugin(app: &mut App) {
     app.init_resource::<ResourceHandles>();
@@ -62,3 +66,40 @@ fn load_resource_assets(world: &mut World) {
         });
     });
 }
+
+/// An [`AssetLoader`] that deserializes assets of type `A` from RON files.
+/// Register it with [`App::register_asset_loader`].
+pub struct RonLoader<A> {
+    extensions: &'static [&'static str],
+    _asset: PhantomData<fn() -> A>,
+}
+
+impl<A> RonLoader<A> {
+    pub fn new(extensions: &'static [&'static str]) -> Self {
+        Self {
+            extensions,
+            _asset: PhantomData,
+        }
+    }
+}
+
+impl<A: Asset + DeserializeOwned> AssetLoader for RonLoader<A> {
+    type Asset = A;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a Self::Settings,
+        _load_context: &'a mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        self.extensions
+    }
+}
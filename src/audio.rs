@@ -1,4 +1,15 @@
-use bevy::prelude::*;
+use bevy::{audio::Volume, prelude::*};
+use serde::{Deserialize, Serialize};
+
+use crate::settings::{RegisterSetting as _, Setting};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_setting::<AudioSettings>();
+    app.add_systems(
+        Update,
+        apply_audio_settings.run_if(resource_changed::<AudioSettings>),
+    );
+}
 
 /// An organizational marker component that should be added to a spawned [`AudioBundle`] if it is in the
 /// general "music" category (ex: global background music, soundtrack, etc).
@@ -35,3 +46,33 @@ pub struct Music;
 /// ```
 #[derive(Component, Default)]
 pub struct SoundEffect;
+
+/// Audio settings that are persisted across launches.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct AudioSettings {
+    /// The volume of all audio, from 0 to 1.
+    pub global_volume: f32,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self { global_volume: 0.3 }
+    }
+}
+
+impl Setting for AudioSettings {
+    const KEY: &'static str = "audio";
+}
+
+fn apply_audio_settings(
+    settings: Res<AudioSettings>,
+    mut global_volume: ResMut<GlobalVolume>,
+    sink_query: Query<&AudioSink>,
+) {
+    global_volume.volume = Volume::new(settings.global_volume);
+    // The global volume only applies to new sounds, so update the playing ones too.
+    for sink in &sink_query {
+        sink.set_volume(settings.global_volume);
+    }
+}
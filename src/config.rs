@@ -0,0 +1,144 @@
+//! Gameplay tunables loaded from `assets/game_config.ron`.
+//!
+//! The [`GameConfig`] resource is available from the start with default values,
+//! and is overwritten once the file has loaded. In native dev builds, the file is
+//! hot-reloaded whenever it is saved, so values can be tuned without recompiling.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::asset_tracking::RonLoader;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<GameConfig>();
+    app.init_asset::<GameConfig>();
+    app.register_asset_loader(RonLoader::<GameConfig>::new(&["ron"]));
+    app.init_resource::<GameConfig>();
+    app.init_resource::<GameConfigHandle>();
+    app.add_systems(PreUpdate, update_game_config);
+}
+
+/// Values that are meant to be tweaked while iterating on the game.
+///
+/// Systems that spawn entities should read from this resource instead of
+/// hardcoding constants. Systems that apply the values to entities that are
+/// already alive can run on [`resource_changed::<GameConfig>`].
+#[derive(Resource, Asset, Reflect, Deserialize, Debug, Clone, PartialEq, Default)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct GameConfig {
+    /// The seed for [`GameRng`](crate::rng::GameRng), or `None` for a random seed.
+    pub seed: Option<u64>,
+    pub player: PlayerConfig,
+    pub splash: SplashConfig,
+    pub spatial_grid: SpatialGridConfig,
+}
+
+impl GameConfig {
+    pub const PATH: &'static str = "game_config.ron";
+}
+
+#[derive(Reflect, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct PlayerConfig {
+    /// See [`MovementController::max_speed`](crate::demo::movement::MovementController::max_speed).
+    pub max_speed: f32,
+    /// Whether to move the player in `FixedUpdate`.
+    /// See [`PhysicalTransform`](crate::demo::movement::PhysicalTransform).
+    pub fixed_timestep: bool,
+    /// Whether to move the player one tile at a time.
+    /// See [`GridMovement`](crate::demo::grid_movement::GridMovement).
+    pub grid_movement: bool,
+    /// How much the player deforms when starting or stopping, or 0 to disable.
+    /// See [`SquashStretch::intensity`](crate::demo::squash_stretch::SquashStretch::intensity).
+    pub squash_stretch: f32,
+}
+
+impl Default for PlayerConfig {
+    fn default() -> Self {
+        Self {
+            max_speed: 400.0,
+            fixed_timestep: false,
+            grid_movement: false,
+            squash_stretch: 0.15,
+        }
+    }
+}
+
+#[derive(Reflect, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct SplashConfig {
+    /// Total duration of the splash screen in seconds.
+    pub duration_secs: f32,
+    /// Duration of the splash image fading in and out in seconds.
+    pub fade_duration_secs: f32,
+}
+
+impl Default for SplashConfig {
+    fn default() -> Self {
+        Self {
+            duration_secs: 1.8,
+            fade_duration_secs: 0.6,
+        }
+    }
+}
+
+impl SplashConfig {
+    /// Replace negative and NaN durations with 0, since they can't be turned
+    /// into a [`Duration`](std::time::Duration).
+    fn sanitize(&mut self) {
+        self.duration_secs = self.duration_secs.max(0.0);
+        self.fade_duration_secs = self.fade_duration_secs.max(0.0);
+    }
+}
+
+#[derive(Reflect, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct SpatialGridConfig {
+    /// The cell size of the [`SpatialGrid`](crate::demo::spatial_grid::SpatialGrid).
+    pub cell_size: f32,
+}
+
+impl Default for SpatialGridConfig {
+    fn default() -> Self {
+        Self { cell_size: 128.0 }
+    }
+}
+
+/// Keeps the config file loaded so that it can be hot-reloaded.
+#[derive(Resource)]
+struct GameConfigHandle(Handle<GameConfig>);
+
+impl FromWorld for GameConfigHandle {
+    fn from_world(world: &mut World) -> Self {
+        let assets = world.resource::<AssetServer>();
+        Self(assets.load(GameConfig::PATH))
+    }
+}
+
+/// Copy the config file into the [`GameConfig`] resource when it is first loaded
+/// or modified on disk.
+fn update_game_config(
+    mut asset_events: EventReader<AssetEvent<GameConfig>>,
+    handle: Res<GameConfigHandle>,
+    assets: Res<Assets<GameConfig>>,
+    mut config: ResMut<GameConfig>,
+) {
+    for event in asset_events.read() {
+        let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = *event
+        else {
+            continue;
+        };
+        if id != handle.0.id() {
+            continue;
+        }
+        let Some(new_config) = assets.get(id) else {
+            continue;
+        };
+        let mut new_config = new_config.clone();
+        new_config.splash.sanitize();
+        // Avoid triggering change detection if nothing changed.
+        config.set_if_neq(new_config);
+        info!("Loaded game config from {}", GameConfig::PATH);
+    }
+}
@@ -1,177 +1,344 @@
-//! Player sprite animation.
+//! Sprite animation driven by named clips.
 //! This is based on multiple examples and may be very different for your game.
 //! - [Sprite flipping](https://github.com/bevyengine/bevy/blob/latest/examples/2d/sprite_flipping.rs)
 //! - [Sprite animation](https://github.com/bevyengine/bevy/blob/latest/examples/2d/sprite_animation.rs)
 //! - [Timers](https://github.com/bevyengine/bevy/blob/latest/examples/time/timers.rs)
+//!
+//! An [`AnimationController`] plays one clip at a time from a set of
+//! [`AnimationClips`] loaded from a RON file in `assets/animations`.
+//! Gameplay code requests transitions with [`AnimationController::set_state`],
+//! and can react to [`AnimationFinished`] and [`AnimationFrame`] events.
+//! The player plays a one-shot `"hurt"` clip when damaged, then goes back to idle.
+//! In native dev builds, the clips are hot-reloaded whenever the file is saved.
 
-use bevy::prelude::*;
-use rand::prelude::*;
 use std::time::Duration;
 
+use bevy::{prelude::*, utils::HashMap};
+use serde::Deserialize;
+
 use crate::{
+    asset_tracking::RonLoader,
     audio::SoundEffect,
-    demo::{movement::MovementController, player::PlayerAssets},
+    config::PlayerConfig,
+    demo::{
+        health::DamageEvent,
+        movement::{update_facing, Facing, MovementController},
+        player::{Player, PlayerAssets},
+    },
+    rng::GameRng,
     AppSet,
 };
 
 pub(super) fn plugin(app: &mut App) {
+    app.register_type::<AnimationController>();
+    app.init_asset::<AnimationClips>();
+    app.register_asset_loader(RonLoader::<AnimationClips>::new(&["ron"]));
+    app.add_event::<AnimationFinished>();
+    app.add_event::<AnimationFrame>();
+
     // Animate and play sound effects based on controls.
-    app.register_type::<PlayerAnimation>();
     app.add_systems(
         Update,
         (
             update_animation_timer.in_set(AppSet::TickTimers),
             (
-                update_animation_movement,
+                update_player_animation_state.after(update_facing),
                 update_animation_atlas,
-                trigger_step_sound_effect,
+                trigger_step_sound_effect.run_if(resource_exists::<PlayerAssets>),
             )
                 .chain()
-                .run_if(resource_exists::<PlayerAssets>)
                 .in_set(AppSet::Update),
         ),
     );
+    app.observe(play_hurt_animation);
 }
 
-/// Update the sprite direction and animation state (idling/walking).
-fn update_animation_movement(
-    mut player_query: Query<(&MovementController, &mut Sprite, &mut PlayerAnimation)>,
-) {
-    for (controller, mut sprite, mut animation) in &mut player_query {
-        let dx = controller.intent.x;
-        if dx != 0.0 {
-            sprite.flip_x = dx < 0.0;
-        }
-
-        let animation_state = if controller.intent == Vec2::ZERO {
-            PlayerAnimationState::Idling
-        } else {
-            PlayerAnimationState::Walking
-        };
-        animation.update_state(animation_state);
-    }
+/// A range of frames in a texture atlas that is played in order.
+#[derive(Reflect, Deserialize, Debug, Clone, PartialEq)]
+pub struct AnimationClip {
+    /// The atlas index of the first frame.
+    pub start_index: usize,
+    pub frame_count: usize,
+    /// Frames per second.
+    pub fps: f32,
+    /// Whether to start over after the last frame instead of holding it.
+    pub looping: bool,
+    /// Frames that send an [`AnimationFrame`] event when they are reached.
+    #[serde(default)]
+    pub tagged_frames: Vec<usize>,
 }
 
-/// Update the animation timer.
-fn update_animation_timer(time: Res<Time>, mut query: Query<&mut PlayerAnimation>) {
-    for mut animation in &mut query {
-        animation.update_timer(time.delta());
+impl AnimationClip {
+    /// The duration of each frame.
+    pub fn frame_duration(&self) -> Duration {
+        Duration::from_secs_f32(1.0 / self.fps.max(f32::EPSILON))
     }
 }
 
-/// Update the texture atlas to reflect changes in the animation.
-fn update_animation_atlas(mut query: Query<(&PlayerAnimation, &mut TextureAtlas)>) {
-    for (animation, mut atlas) in &mut query {
-        if animation.changed() {
-            atlas.index = animation.get_atlas_index();
-        }
-    }
+/// Sent when a non-looping clip reaches its last frame.
+#[derive(Event, Debug, Clone)]
+pub struct AnimationFinished {
+    pub entity: Entity,
+    /// The name of the clip that finished.
+    pub clip: String,
 }
 
-/// If the player is moving, play a step sound effect synchronized with the
-/// animation.
-fn trigger_step_sound_effect(
-    mut commands: Commands,
-    player_assets: Res<PlayerAssets>,
-    mut step_query: Query<&PlayerAnimation>,
-) {
-    for animation in &mut step_query {
-        if animation.state == PlayerAnimationState::Walking
-            && animation.changed()
-            && (animation.frame == 2 || animation.frame == 5)
-        {
-            let rng = &mut rand::thread_rng();
-            let random_step = player_assets.steps.choose(rng).unwrap();
-            commands.spawn((
-                AudioBundle {
-                    source: random_step.clone(),
-                    settings: PlaybackSettings::DESPAWN,
-                },
-                SoundEffect,
-            ));
-        }
-    }
+/// Sent when a clip advances to one of its [`AnimationClip::tagged_frames`].
+#[derive(Event, Debug, Clone, Copy)]
+pub struct AnimationFrame {
+    pub entity: Entity,
+    /// The frame within the clip, starting at 0.
+    pub frame: usize,
 }
 
-/// Component that tracks player's animation state.
-/// It is tightly bound to the texture atlas we use.
+/// A set of [`AnimationClip`]s by name, e.g. `"idle"` and `"walk"`.
+#[derive(Asset, Reflect, Deserialize, Debug, Clone, Default)]
+pub struct AnimationClips(pub HashMap<String, AnimationClip>);
+
+/// Plays the clip for its current state from a set of [`AnimationClips`].
 #[derive(Component, Reflect)]
 #[reflect(Component)]
-pub struct PlayerAnimation {
-    timer: Timer,
+pub struct AnimationController {
+    clips: Handle<AnimationClips>,
+    state: String,
+    /// The current frame within the clip.
     frame: usize,
-    state: PlayerAnimationState,
-}
-
-#[derive(Reflect, PartialEq)]
-pub enum PlayerAnimationState {
-    Idling,
-    Walking,
+    timer: Timer,
+    /// Whether the shown frame changed during this tick.
+    changed: bool,
+    /// Whether a non-looping clip reached its last frame.
+    finished: bool,
+    /// Multiplies the frame rate of the clip. At 0, the current frame is held.
+    pub playback_speed: f32,
 }
 
-impl PlayerAnimation {
-    /// The number of idle frames.
-    const IDLE_FRAMES: usize = 2;
-    /// The duration of each idle frame.
-    const IDLE_INTERVAL: Duration = Duration::from_millis(500);
-    /// The number of walking frames.
-    const WALKING_FRAMES: usize = 6;
-    /// The duration of each walking frame.
-    const WALKING_INTERVAL: Duration = Duration::from_millis(50);
-
-    fn idling() -> Self {
+impl AnimationController {
+    /// Start playing the clip named `state`.
+    pub fn new(clips: Handle<AnimationClips>, state: impl Into<String>) -> Self {
         Self {
-            timer: Timer::new(Self::IDLE_INTERVAL, TimerMode::Repeating),
+            clips,
+            state: state.into(),
             frame: 0,
-            state: PlayerAnimationState::Idling,
+            timer: Timer::default(),
+            changed: true,
+            finished: false,
+            playback_speed: 1.0,
         }
     }
 
-    fn walking() -> Self {
-        Self {
-            timer: Timer::new(Self::WALKING_INTERVAL, TimerMode::Repeating),
-            frame: 0,
-            state: PlayerAnimationState::Walking,
-        }
+    /// The name of the current clip.
+    pub fn state(&self) -> &str {
+        &self.state
+    }
+
+    /// Whether the shown frame changed during this tick.
+    pub fn changed(&self) -> bool {
+        self.changed
     }
 
-    pub fn new() -> Self {
-        Self::idling()
+    /// Whether a non-looping clip is holding its last frame.
+    pub fn is_finished(&self) -> bool {
+        self.finished
     }
 
-    /// Update animation timers.
-    pub fn update_timer(&mut self, delta: Duration) {
-        self.timer.tick(delta);
-        if !self.timer.finished() {
+    /// Switch to the clip named `state`, starting from its first frame.
+    /// Requesting the current state again does nothing.
+    pub fn set_state(&mut self, state: &str) {
+        if self.state != state {
+            self.state = state.to_string();
+            self.frame = 0;
+            self.timer.reset();
+            self.changed = true;
+            self.finished = false;
+        }
+    }
+
+    /// Advance the current clip by `delta`, calling `on_frame` for every frame
+    /// that is reached.
+    pub fn update_timer(
+        &mut self,
+        clip: &AnimationClip,
+        delta: Duration,
+        mut on_frame: impl FnMut(usize),
+    ) {
+        if self.finished {
             return;
         }
-        self.frame = (self.frame + 1)
-            % match self.state {
-                PlayerAnimationState::Idling => Self::IDLE_FRAMES,
-                PlayerAnimationState::Walking => Self::WALKING_FRAMES,
-            };
+        self.timer.set_duration(clip.frame_duration());
+        self.timer.set_mode(TimerMode::Repeating);
+        self.timer.tick(delta.mul_f32(self.playback_speed.max(0.0)));
+        for _ in 0..self.timer.times_finished_this_tick() {
+            if self.frame + 1 < clip.frame_count {
+                self.frame += 1;
+            } else if clip.looping {
+                self.frame = 0;
+            } else {
+                self.finished = true;
+                break;
+            }
+            self.changed = true;
+            on_frame(self.frame);
+        }
     }
 
-    /// Update animation state if it changes.
-    pub fn update_state(&mut self, state: PlayerAnimationState) {
-        if self.state != state {
-            match state {
-                PlayerAnimationState::Idling => *self = Self::idling(),
-                PlayerAnimationState::Walking => *self = Self::walking(),
+    /// Return sprite index in the atlas.
+    pub fn atlas_index(&self, clip: &AnimationClip) -> usize {
+        clip.start_index + self.frame.min(clip.frame_count.saturating_sub(1))
+    }
+}
+
+/// Update the animation timers and send events for finished clips and tagged frames.
+fn update_animation_timer(
+    time: Res<Time>,
+    clips: Res<Assets<AnimationClips>>,
+    mut query: Query<(Entity, &mut AnimationController)>,
+    mut finished_events: EventWriter<AnimationFinished>,
+    mut frame_events: EventWriter<AnimationFrame>,
+) {
+    for (entity, mut animation) in &mut query {
+        animation.changed = false;
+        let Some(clip) = clips
+            .get(&animation.clips)
+            .and_then(|clips| clips.0.get(&animation.state))
+        else {
+            continue;
+        };
+
+        let was_finished = animation.finished;
+        animation.update_timer(clip, time.delta(), |frame| {
+            if clip.tagged_frames.contains(&frame) {
+                frame_events.send(AnimationFrame { entity, frame });
             }
+        });
+        if animation.finished && !was_finished {
+            finished_events.send(AnimationFinished {
+                entity,
+                clip: animation.state.clone(),
+            });
         }
     }
+}
 
-    /// Whether animation changed this tick.
-    pub fn changed(&self) -> bool {
-        self.timer.finished()
+/// The range of playback speeds of the walking clip, so that very slow or
+/// fast movement still looks reasonable.
+const WALK_PLAYBACK_SPEED: (f32, f32) = (0.5, 2.0);
+
+/// The one-shot clip the player plays when damaged.
+const HURT_CLIP: &str = "hurt";
+
+fn play_hurt_animation(
+    trigger: Trigger<DamageEvent>,
+    mut player_query: Query<&mut AnimationController, With<Player>>,
+) {
+    if let Ok(mut animation) = player_query.get_mut(trigger.entity()) {
+        animation.set_state(HURT_CLIP);
     }
+}
 
-    /// Return sprite index in the atlas.
-    pub fn get_atlas_index(&self) -> usize {
-        match self.state {
-            PlayerAnimationState::Idling => self.frame,
-            PlayerAnimationState::Walking => 6 + self.frame,
+/// Update the player's sprite direction and animation state.
+/// Standing still plays the idle clip for the current [`Facing`], e.g.
+/// `"idle_left"`, or `"idle"` if there is no clip for that direction.
+fn update_player_animation_state(
+    clips: Res<Assets<AnimationClips>>,
+    mut player_query: Query<
+        (
+            &MovementController,
+            &Facing,
+            &mut Sprite,
+            &mut AnimationController,
+        ),
+        With<Player>,
+    >,
+) {
+    for (controller, facing, mut sprite, mut animation) in &mut player_query {
+        // The sprite sheet only faces right, so flip it to face left.
+        // Facing up or down keeps the last horizontal direction.
+        let flip_x = match facing {
+            Facing::Left => true,
+            Facing::Right => false,
+            Facing::Up | Facing::Down => sprite.flip_x,
+        };
+        // Avoid triggering change detection if nothing changed.
+        if sprite.flip_x != flip_x {
+            sprite.flip_x = flip_x;
+        }
+
+        // Let the hurt clip finish before moving on.
+        if animation.state() == HURT_CLIP && !animation.is_finished() {
+            continue;
+        }
+
+        // The walking clip plays at its own frame rate at the default player
+        // speed, and cycles frames faster or slower when moving faster or slower.
+        let speed = controller.max_speed * controller.intent.length();
+        let reference_speed = PlayerConfig::default().max_speed;
+        animation.playback_speed = if speed > 0.0 {
+            (speed / reference_speed).clamp(WALK_PLAYBACK_SPEED.0, WALK_PLAYBACK_SPEED.1)
+        } else {
+            // Idle clips play at their own frame rate.
+            1.0
+        };
+        if controller.intent != Vec2::ZERO {
+            animation.set_state("walk");
+            continue;
+        }
+        let directional_idle = match facing {
+            Facing::Left => "idle_left",
+            Facing::Right => "idle_right",
+            Facing::Up => "idle_up",
+            Facing::Down => "idle_down",
+        };
+        let has_directional_idle = clips
+            .get(&animation.clips)
+            .is_some_and(|clips| clips.0.contains_key(directional_idle));
+        animation.set_state(if has_directional_idle {
+            directional_idle
+        } else {
+            "idle"
+        });
+    }
+}
+
+/// Update the texture atlas to reflect changes in the animation.
+fn update_animation_atlas(
+    clips: Res<Assets<AnimationClips>>,
+    mut query: Query<(&AnimationController, &mut TextureAtlas)>,
+) {
+    for (animation, mut atlas) in &mut query {
+        if !animation.changed() {
+            continue;
+        }
+        let Some(clip) = clips
+            .get(&animation.clips)
+            .and_then(|clips| clips.0.get(&animation.state))
+        else {
+            continue;
+        };
+        atlas.index = animation.atlas_index(clip);
+    }
+}
+
+/// Play a step sound effect on the player's tagged animation frames,
+/// which are the frames of the walking animation where a foot lands.
+fn trigger_step_sound_effect(
+    mut commands: Commands,
+    player_assets: Res<PlayerAssets>,
+    mut rng: ResMut<GameRng>,
+    mut frame_events: EventReader<AnimationFrame>,
+    player_query: Query<(), With<Player>>,
+) {
+    for event in frame_events.read() {
+        if player_query.contains(event.entity) {
+            let Some(random_step) = rng.pick(&player_assets.steps) else {
+                continue;
+            };
+            commands.spawn((
+                AudioBundle {
+                    source: random_step.clone(),
+                    settings: PlaybackSettings::DESPAWN,
+                },
+                SoundEffect,
+            ));
         }
     }
 }
@@ -0,0 +1,197 @@
+//! Detect overlaps between axis-aligned bounding boxes.
+//!
+//! Every frame, each pair of overlapping [`Collider`]s produces a
+//! [`CollisionEvent`]. It is both sent as a regular event and triggered on
+//! the two colliding entities, so it can be handled with an `EventReader` or
+//! with an observer on one of the entities.
+//!
+//! Only colliders that share a cell in the [`SpatialGrid`] are checked against
+//! each other.
+//!
+//! Moving entities with a collider are blocked by [`Solid`] colliders, sliding
+//! along them when moving diagonally.
+
+use bevy::prelude::*;
+
+use crate::{
+    demo::{
+        grid_movement::GridMovement,
+        movement::{apply_movement, apply_screen_wrap, MovementController, PhysicalTransform},
+        spatial_grid::{update_spatial_grid, SpatialGrid},
+    },
+    AppSet,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<(Collider, Solid)>();
+    app.add_event::<CollisionEvent>();
+    app.add_systems(
+        Update,
+        (
+            record_previous_position.before(apply_movement),
+            resolve_solid_collisions
+                .after(apply_movement)
+                .before(apply_screen_wrap),
+            detect_collisions.after(update_spatial_grid),
+        )
+            .in_set(AppSet::Update),
+    );
+}
+
+/// An axis-aligned bounding box centered on the entity's translation.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+pub struct Collider {
+    /// Half the width and height of the box in world units.
+    /// This is not affected by the entity's scale.
+    pub half_extents: Vec2,
+}
+
+impl Collider {
+    pub fn new(half_extents: Vec2) -> Self {
+        Self { half_extents }
+    }
+
+    /// The bounding box of this collider centered at `position`.
+    pub fn aabb(&self, position: Vec2) -> Rect {
+        Rect::from_center_half_size(position, self.half_extents)
+    }
+}
+
+/// A [`Collider`] that blocks the movement of other colliders.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Component)]
+pub struct Solid;
+
+/// Two [`Collider`]s are overlapping this frame.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollisionEvent {
+    pub a: Entity,
+    pub b: Entity,
+}
+
+impl CollisionEvent {
+    /// The entity colliding with `entity`, which must be one of the pair.
+    pub fn other(&self, entity: Entity) -> Entity {
+        if entity == self.a {
+            self.b
+        } else {
+            self.a
+        }
+    }
+}
+
+/// Whether two bounding boxes overlap. Boxes that only touch do not overlap.
+pub fn overlaps(a: Rect, b: Rect) -> bool {
+    !a.intersect(b).is_empty()
+}
+
+pub(super) fn detect_collisions(
+    mut commands: Commands,
+    grid: Res<SpatialGrid>,
+    collider_query: Query<(Entity, &Collider, &Transform)>,
+    mut collision_events: EventWriter<CollisionEvent>,
+) {
+    for (a, a_collider, a_transform) in &collider_query {
+        let a_aabb = a_collider.aabb(a_transform.translation.xy());
+        for b in grid.query_region(a_aabb) {
+            // Only check each pair once.
+            if b <= a {
+                continue;
+            }
+            let Ok((_, b_collider, b_transform)) = collider_query.get(b) else {
+                continue;
+            };
+            if !overlaps(a_aabb, b_collider.aabb(b_transform.translation.xy())) {
+                continue;
+            }
+            let event = CollisionEvent { a, b };
+            collision_events.send(event);
+            commands.trigger_targets(event, [a, b]);
+        }
+    }
+}
+
+/// The position of a moving collider before it moved this frame.
+#[derive(Component, Debug)]
+struct PreviousPosition(Vec2);
+
+fn record_previous_position(
+    mut commands: Commands,
+    mut mover_query: Query<
+        (Entity, &Transform, Option<&mut PreviousPosition>),
+        (
+            With<MovementController>,
+            With<Collider>,
+            Without<Solid>,
+            Without<PhysicalTransform>,
+            Without<GridMovement>,
+        ),
+    >,
+) {
+    for (entity, transform, previous) in &mut mover_query {
+        let position = transform.translation.xy();
+        match previous {
+            Some(mut previous) => previous.0 = position,
+            None => {
+                commands.entity(entity).insert(PreviousPosition(position));
+            }
+        }
+    }
+}
+
+/// Push moving colliders out of [`Solid`] colliders. The movement is resolved
+/// one axis at a time, so the blocked axis is undone while the other one
+/// still applies, which makes the collider slide along walls.
+pub(super) fn resolve_solid_collisions(
+    solid_query: Query<(&Collider, &Transform), With<Solid>>,
+    mut mover_query: Query<(&Collider, &mut Transform, &PreviousPosition), Without<Solid>>,
+) {
+    let solids = solid_query
+        .iter()
+        .map(|(collider, transform)| collider.aabb(transform.translation.xy()))
+        .collect::<Vec<_>>();
+    if solids.is_empty() {
+        return;
+    }
+
+    for (collider, mut transform, previous) in &mut mover_query {
+        let target = transform.translation.xy();
+        let mut position = previous.0;
+        for axis in 0..2 {
+            position[axis] = target[axis];
+            let movement = target[axis] - previous.0[axis];
+            position[axis] = push_out_of_solids(position, collider, &solids, axis, movement);
+        }
+        transform.translation = position.extend(transform.translation.z);
+    }
+}
+
+/// Return the coordinate along `axis` at which the collider no longer overlaps any solid.
+/// The collider is pushed back against its movement, or out the nearest side if it
+/// didn't move along this axis.
+fn push_out_of_solids(
+    mut position: Vec2,
+    collider: &Collider,
+    solids: &[Rect],
+    axis: usize,
+    movement: f32,
+) -> f32 {
+    for solid in solids {
+        if !overlaps(collider.aabb(position), *solid) {
+            continue;
+        }
+        let before = solid.min[axis] - collider.half_extents[axis];
+        let after = solid.max[axis] + collider.half_extents[axis];
+        position[axis] = if movement > 0.0 {
+            before
+        } else if movement < 0.0 {
+            after
+        } else if position[axis] - before < after - position[axis] {
+            before
+        } else {
+            after
+        };
+    }
+    position[axis]
+}
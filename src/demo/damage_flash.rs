@@ -0,0 +1,83 @@
+//! Flash a sprite when it takes damage.
+//!
+//! Flashes are disabled with [`AccessibilitySettings::reduced_motion`](crate::accessibility::AccessibilitySettings::reduced_motion).
+
+use bevy::prelude::*;
+
+use crate::{accessibility::AccessibilitySettings, demo::health::DamageEvent, AppSet};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<DamageFlash>();
+    app.add_systems(Update, tick_damage_flash.in_set(AppSet::TickTimers));
+    app.observe(start_damage_flash);
+}
+
+/// Sprite colors are multiplied with the texture, so components above 1
+/// brighten the texture towards white.
+pub const WHITE_FLASH: Color = Color::srgb(8.0, 8.0, 8.0);
+
+/// Override the sprite color for a moment whenever the entity takes damage.
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component)]
+pub struct DamageFlash {
+    pub color: Color,
+    timer: Timer,
+    /// The sprite color to restore once the flash is over,
+    /// or `None` if the sprite isn't flashing.
+    original: Option<Color>,
+}
+
+impl DamageFlash {
+    pub fn new(color: Color, duration_secs: f32) -> Self {
+        Self {
+            color,
+            timer: Timer::from_seconds(duration_secs, TimerMode::Once),
+            original: None,
+        }
+    }
+
+    pub fn is_flashing(&self) -> bool {
+        self.original.is_some()
+    }
+
+    /// Change the color that is restored once the current flash is over.
+    /// Does nothing if the sprite isn't flashing.
+    pub fn set_restore_color(&mut self, color: Color) {
+        if let Some(original) = &mut self.original {
+            *original = color;
+        }
+    }
+}
+
+fn start_damage_flash(
+    trigger: Trigger<DamageEvent>,
+    accessibility: Res<AccessibilitySettings>,
+    mut flash_query: Query<(&mut DamageFlash, &mut Sprite)>,
+) {
+    if accessibility.reduced_motion {
+        return;
+    }
+    let Ok((mut flash, mut sprite)) = flash_query.get_mut(trigger.entity()) else {
+        return;
+    };
+
+    // Overlapping hits restart the flash, but keep the color from before the first one.
+    if flash.original.is_none() {
+        flash.original = Some(sprite.color);
+    }
+    flash.timer.reset();
+    sprite.color = flash.color;
+}
+
+fn tick_damage_flash(time: Res<Time>, mut flash_query: Query<(&mut DamageFlash, &mut Sprite)>) {
+    for (mut flash, mut sprite) in &mut flash_query {
+        if !flash.is_flashing() {
+            continue;
+        }
+        if flash.timer.tick(time.delta()).finished() {
+            if let Some(original) = flash.original.take() {
+                sprite.color = original;
+            }
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! Enemies that chase the player.
+//!
+//! The AI only sets the [`MovementController`] intent, just like player input
+//! does for the player. Enemies with a [`Patrol`] follow it until they see a
+//! player. To give enemies smarter behavior, replace [`ChasePlayer`] with your
+//! own component and system that set the intent.
+
+use std::time::Duration;
+
+use bevy::{ecs::world::Command, prelude::*};
+
+use crate::{
+    demo::{
+        collision::Collider,
+        damage_flash::{DamageFlash, WHITE_FLASH},
+        health::{DeathEvent, Health},
+        knockback::DamageOnContact,
+        line_of_sight::LineOfSight,
+        movement::{apply_movement, MovementController},
+        patrol::{follow_patrol, Patrol},
+        pickup::{Pickup, SpawnPickup},
+        player::{Player, PlayerAssets},
+        sprite_variant::SpriteVariant,
+    },
+    hit_stop::HitStop,
+    save::quicksave::Persistent,
+    screens::Screen,
+    spawn::SpawnWith as _,
+    AppSet,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<(Enemy, ChasePlayer)>();
+
+    // Decide where to move before moving.
+    app.add_systems(
+        Update,
+        chase_player
+            .after(follow_patrol)
+            .before(apply_movement)
+            .in_set(AppSet::Update),
+    );
+
+    app.observe(despawn_dead_enemy);
+}
+
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Component)]
+pub struct Enemy;
+
+/// Move in a straight line toward the nearest [`Player`] in sight.
+/// If no player is in sight, stand still or follow the entity's [`Patrol`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct ChasePlayer {
+    /// The speed while chasing in world units per second.
+    /// This overrides [`MovementController::max_speed`].
+    pub speed: f32,
+}
+
+/// A command to spawn an enemy.
+#[derive(Debug)]
+pub struct SpawnEnemy {
+    /// See [`ChasePlayer::speed`].
+    pub speed: f32,
+    /// Where to spawn the enemy in world units.
+    pub position: Vec2,
+    /// Waypoints to walk back and forth between while no player is in sight.
+    /// Leave this empty to stand still instead.
+    pub patrol: Vec<Vec2>,
+}
+
+impl Command for SpawnEnemy {
+    fn apply(self, world: &mut World) {
+        world.spawn_with_data(self, spawn_enemy);
+    }
+}
+
+/// Draw enemies behind the player.
+const ENEMY_Z: f32 = 0.5;
+
+/// Enemies are tinted versions of the player sprite.
+const ENEMY_COLOR: Color = Color::srgb(1.0, 0.4, 0.4);
+
+fn spawn_enemy(
+    In(config): In<SpawnEnemy>,
+    mut commands: Commands,
+    player_assets: Res<PlayerAssets>,
+    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    let layout = TextureAtlasLayout::from_grid(UVec2::splat(32), 6, 2, Some(UVec2::splat(1)), None);
+    let texture_atlas_layout = texture_atlas_layouts.add(layout);
+
+    let mut enemy = commands.spawn((
+        Name::new("Enemy"),
+        Enemy,
+        SpriteBundle {
+            texture: player_assets.ducky.clone(),
+            transform: Transform::from_translation(config.position.extend(ENEMY_Z))
+                .with_scale(Vec2::splat(6.0).extend(1.0)),
+            ..default()
+        },
+        TextureAtlas {
+            layout: texture_atlas_layout,
+            index: 0,
+        },
+        SpriteVariant { tint: ENEMY_COLOR },
+        MovementController {
+            max_speed: config.speed,
+            ..default()
+        },
+        ChasePlayer {
+            speed: config.speed,
+        },
+        Collider::new(Vec2::new(36.0, 48.0)),
+        Health::new(30.0),
+        DamageFlash::new(WHITE_FLASH, 0.1),
+        DamageOnContact {
+            amount: 10.0,
+            knockback: 800.0,
+        },
+        Persistent,
+        StateScoped(Screen::Gameplay),
+    ));
+    if !config.patrol.is_empty() {
+        enemy.insert(Patrol::new(
+            config.patrol,
+            config.speed * PATROL_SPEED_FACTOR,
+            false,
+        ));
+    }
+}
+
+/// Enemies patrol slower than they chase.
+const PATROL_SPEED_FACTOR: f32 = 0.5;
+
+fn chase_player(
+    line_of_sight: LineOfSight,
+    player_query: Query<&Transform, With<Player>>,
+    mut chaser_query: Query<
+        (
+            &ChasePlayer,
+            &Transform,
+            &mut MovementController,
+            Has<Patrol>,
+        ),
+        Without<Player>,
+    >,
+) {
+    for (chase, transform, mut controller, patrols) in &mut chaser_query {
+        let position = transform.translation.xy();
+        let nearest_player = player_query
+            .iter()
+            .map(|player| player.translation.xy())
+            .filter(|&player| line_of_sight.has_line_of_sight(position, player))
+            .min_by(|a, b| {
+                a.distance_squared(position)
+                    .total_cmp(&b.distance_squared(position))
+            });
+
+        match nearest_player {
+            Some(target) => {
+                controller.max_speed = chase.speed;
+                controller.intent = (target - position).normalize_or_zero();
+            }
+            // Keep the intent set by the patrol.
+            None if patrols => {}
+            None => controller.intent = Vec2::ZERO,
+        }
+    }
+}
+
+/// How long to freeze gameplay when an enemy dies.
+const ENEMY_DEATH_HIT_STOP: Duration = Duration::from_millis(80);
+
+/// How far above a dead enemy its coin starts falling.
+const ENEMY_DROP_HEIGHT: f32 = 48.0;
+
+fn despawn_dead_enemy(
+    trigger: Trigger<DeathEvent>,
+    mut commands: Commands,
+    enemy_query: Query<&Transform, With<Enemy>>,
+    mut hit_stop_events: EventWriter<HitStop>,
+) {
+    let entity = trigger.entity();
+    let Ok(transform) = enemy_query.get(entity) else {
+        return;
+    };
+    commands.entity(entity).despawn_recursive();
+    hit_stop_events.send(HitStop {
+        duration: ENEMY_DEATH_HIT_STOP,
+    });
+    commands.add(SpawnPickup {
+        pickup: Pickup {
+            kind: "coin".to_string(),
+            value: 1,
+        },
+        position: transform.translation.xy(),
+        drop_height: ENEMY_DROP_HEIGHT,
+    });
+}
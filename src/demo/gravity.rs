@@ -0,0 +1,80 @@
+//! Make entities fall with a reusable gravity component.
+//!
+//! Gravity is applied in `FixedUpdate` on top of any other movement, so an entity
+//! with both a [`MovementController`](crate::demo::movement::MovementController)
+//! and [`AffectedByGravity`] can be steered horizontally while it falls.
+//!
+//! There is no ground detection yet. Trigger [`Landed`] on an entity from your
+//! own ground check to stop its fall, like coins dropped by enemies do in
+//! [`pickup`](crate::demo::pickup).
+
+use bevy::prelude::*;
+
+use crate::{
+    demo::movement::{apply_fixed_movement, PhysicalTransform},
+    AppSet,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<AffectedByGravity>();
+    // Fall after moving so that the fall is part of the interpolated movement.
+    app.add_systems(
+        FixedUpdate,
+        apply_gravity
+            .after(apply_fixed_movement)
+            .in_set(AppSet::Update),
+    );
+    app.observe(stop_falling);
+}
+
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+pub struct AffectedByGravity {
+    /// Downward acceleration in world units per second squared.
+    pub acceleration: f32,
+    /// The maximum falling speed in world units per second.
+    pub terminal: f32,
+    /// The current falling speed in world units per second.
+    pub velocity: f32,
+}
+
+impl AffectedByGravity {
+    pub fn new(acceleration: f32, terminal: f32) -> Self {
+        Self {
+            acceleration,
+            terminal,
+            velocity: 0.0,
+        }
+    }
+}
+
+/// Event triggered on an entity that has hit the ground.
+/// This resets the falling speed of its [`AffectedByGravity`].
+#[derive(Event, Debug)]
+pub struct Landed;
+
+pub(super) fn apply_gravity(
+    time: Res<Time>,
+    mut gravity_query: Query<(
+        &mut AffectedByGravity,
+        &mut Transform,
+        Option<&mut PhysicalTransform>,
+    )>,
+) {
+    let dt = time.delta_seconds();
+    for (mut gravity, mut transform, physical) in &mut gravity_query {
+        gravity.velocity = (gravity.velocity + gravity.acceleration * dt).min(gravity.terminal);
+        let fall = gravity.velocity * dt;
+        // Entities moving in `FixedUpdate` have their `Transform` interpolated from this.
+        match physical {
+            Some(mut physical) => physical.translation.y -= fall,
+            None => transform.translation.y -= fall,
+        }
+    }
+}
+
+fn stop_falling(trigger: Trigger<Landed>, mut gravity_query: Query<&mut AffectedByGravity>) {
+    if let Ok(mut gravity) = gravity_query.get_mut(trigger.entity()) {
+        gravity.velocity = 0.0;
+    }
+}
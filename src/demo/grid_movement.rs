@@ -0,0 +1,128 @@
+//! Move one tile at a time, as in many puzzle games.
+//!
+//! Entities with [`GridMovement`] ignore the continuous movement of the
+//! `movement` module. Instead, they step exactly one tile in the direction of
+//! their [`MovementController`] intent, and ignore further input until the step
+//! is done. Steps into [`Solid`] colliders are rejected.
+
+use bevy::prelude::*;
+
+use crate::{
+    demo::{
+        collision::{Collider, Solid},
+        movement::{apply_screen_wrap, MovementController},
+    },
+    easing::Easing,
+    AppSet,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<GridMovement>();
+    app.add_systems(
+        Update,
+        (start_grid_steps, apply_grid_steps)
+            .chain()
+            .before(apply_screen_wrap)
+            .in_set(AppSet::Update)
+            .run_if(any_with_component::<GridMovement>),
+    );
+}
+
+/// Move in steps of one tile instead of continuously.
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component)]
+pub struct GridMovement {
+    /// The width and height of a tile in world units.
+    pub tile_size: f32,
+    /// The center of any tile. Positions are snapped relative to it.
+    pub origin: Vec2,
+    /// How long a single step takes in seconds.
+    pub step_secs: f32,
+    /// How the position moves between two tiles over the course of a step.
+    pub easing: Easing,
+    step: Option<GridStep>,
+}
+
+#[derive(Reflect, Debug, Clone, PartialEq)]
+struct GridStep {
+    from: Vec2,
+    to: Vec2,
+    timer: Timer,
+}
+
+impl GridMovement {
+    pub fn new(tile_size: f32, origin: Vec2) -> Self {
+        Self {
+            tile_size,
+            origin,
+            step_secs: 0.15,
+            easing: Easing::QuadOut,
+            step: None,
+        }
+    }
+
+    /// Whether the entity is currently moving between two tiles.
+    pub fn is_stepping(&self) -> bool {
+        self.step.is_some()
+    }
+
+    /// The center of the tile containing `position`.
+    pub fn snap(&self, position: Vec2) -> Vec2 {
+        self.origin + ((position - self.origin) / self.tile_size).round() * self.tile_size
+    }
+}
+
+/// The cardinal direction closest to `intent`, or `None` if there is no intent.
+fn step_direction(intent: Vec2) -> Option<Vec2> {
+    if intent == Vec2::ZERO {
+        None
+    } else if intent.x.abs() > intent.y.abs() {
+        Some(Vec2::new(intent.x.signum(), 0.0))
+    } else {
+        Some(Vec2::new(0.0, intent.y.signum()))
+    }
+}
+
+fn start_grid_steps(
+    solid_query: Query<(&Collider, &Transform), With<Solid>>,
+    mut grid_query: Query<(&mut GridMovement, &MovementController, &Transform), Without<Solid>>,
+) {
+    for (mut grid, controller, transform) in &mut grid_query {
+        if grid.is_stepping() {
+            continue;
+        }
+        let Some(direction) = step_direction(controller.intent) else {
+            continue;
+        };
+
+        let from = grid.snap(transform.translation.xy());
+        let to = from + direction * grid.tile_size;
+        let blocked = solid_query.iter().any(|(collider, solid_transform)| {
+            collider.aabb(solid_transform.translation.xy()).contains(to)
+        });
+        if blocked {
+            continue;
+        }
+
+        grid.step = Some(GridStep {
+            from,
+            to,
+            timer: Timer::from_seconds(grid.step_secs, TimerMode::Once),
+        });
+    }
+}
+
+fn apply_grid_steps(time: Res<Time>, mut grid_query: Query<(&mut GridMovement, &mut Transform)>) {
+    for (mut grid, mut transform) in &mut grid_query {
+        let easing = grid.easing;
+        let Some(step) = &mut grid.step else {
+            continue;
+        };
+        step.timer.tick(time.delta());
+        let position = step.from.lerp(step.to, easing.apply(step.timer.fraction()));
+        transform.translation = position.extend(transform.translation.z);
+        if step.timer.finished() {
+            grid.step = None;
+        }
+    }
+}
@@ -0,0 +1,140 @@
+//! Health, damage, and death.
+//!
+//! Send a [`DamageEvent`] or [`HealEvent`] to change an entity's [`Health`].
+//! Damage that is applied is also triggered on the damaged entity.
+//! When its health reaches zero, a [`DeathEvent`] is sent and triggered on it.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::AppSet;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<(Health, Invulnerability)>();
+    app.add_event::<DamageEvent>();
+    app.add_event::<HealEvent>();
+    app.add_event::<DeathEvent>();
+    app.add_systems(
+        Update,
+        (
+            tick_invulnerability.in_set(AppSet::TickTimers),
+            (apply_heal, apply_damage).chain().in_set(AppSet::Update),
+        ),
+    );
+}
+
+#[derive(Component, Reflect, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+pub struct Health {
+    /// Always between 0 and [`Self::max`].
+    pub current: f32,
+    pub max: f32,
+}
+
+impl Health {
+    pub fn new(max: f32) -> Self {
+        Self { current: max, max }
+    }
+
+    pub fn is_dead(&self) -> bool {
+        self.current <= 0.0
+    }
+}
+
+/// Ignore damage for a while after being hit, so that overlapping hits
+/// don't kill an entity instantly.
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component)]
+pub struct Invulnerability {
+    timer: Timer,
+}
+
+impl Invulnerability {
+    /// Become invulnerable for `duration_secs` after every hit.
+    pub fn new(duration_secs: f32) -> Self {
+        let mut timer = Timer::from_seconds(duration_secs, TimerMode::Once);
+        // Start out vulnerable.
+        timer.tick(timer.duration());
+        Self { timer }
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.timer.finished()
+    }
+
+    /// Become invulnerable for the full duration, starting now.
+    pub fn start(&mut self) {
+        self.timer.reset();
+    }
+}
+
+/// Sent to damage an entity. Triggered on the entity once the damage is applied,
+/// so ignored hits, e.g. during [`Invulnerability`], are not triggered.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct DamageEvent {
+    pub target: Entity,
+    pub amount: f32,
+}
+
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct HealEvent {
+    pub target: Entity,
+    pub amount: f32,
+}
+
+/// An entity's health reached zero.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeathEvent {
+    pub entity: Entity,
+}
+
+fn tick_invulnerability(time: Res<Time>, mut query: Query<&mut Invulnerability>) {
+    for mut invulnerability in &mut query {
+        invulnerability.timer.tick(time.delta());
+    }
+}
+
+fn apply_heal(mut heal_events: EventReader<HealEvent>, mut health_query: Query<&mut Health>) {
+    for event in heal_events.read() {
+        let Ok(mut health) = health_query.get_mut(event.target) else {
+            continue;
+        };
+        // The dead stay dead.
+        if health.is_dead() {
+            continue;
+        }
+        health.current = (health.current + event.amount).clamp(0.0, health.max);
+    }
+}
+
+pub(super) fn apply_damage(
+    mut commands: Commands,
+    mut damage_events: EventReader<DamageEvent>,
+    mut health_query: Query<(&mut Health, Option<&mut Invulnerability>)>,
+    mut death_events: EventWriter<DeathEvent>,
+) {
+    for event in damage_events.read() {
+        let Ok((mut health, invulnerability)) = health_query.get_mut(event.target) else {
+            continue;
+        };
+        if health.is_dead() {
+            continue;
+        }
+        if let Some(mut invulnerability) = invulnerability {
+            if invulnerability.is_active() {
+                continue;
+            }
+            invulnerability.start();
+        }
+
+        health.current = (health.current - event.amount).clamp(0.0, health.max);
+        commands.trigger_targets(*event, event.target);
+        if health.is_dead() {
+            let event = DeathEvent {
+                entity: event.target,
+            };
+            death_events.send(event);
+            commands.trigger_targets(event, event.entity);
+        }
+    }
+}
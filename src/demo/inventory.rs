@@ -0,0 +1,100 @@
+//! Item storage for RPG-like games.
+//!
+//! Items are identified by string keys, so your game can define its own items
+//! without changing [`Inventory`]. Collecting a [`Pickup`](crate::demo::pickup::Pickup)
+//! adds it to the collector's inventory.
+
+use bevy::{prelude::*, utils::HashMap};
+use serde::{Deserialize, Serialize};
+
+use crate::{demo::pickup::PickupCollected, AppSet};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Inventory>();
+    app.add_event::<InventoryChanged>();
+    app.add_systems(Update, store_pickups.in_set(AppSet::Update));
+}
+
+/// Counts of items by key.
+#[derive(Component, Reflect, Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct Inventory {
+    items: HashMap<String, u32>,
+    /// The maximum count per item. Items without a limit can stack indefinitely.
+    stack_limits: HashMap<String, u32>,
+}
+
+impl Inventory {
+    /// Limit how many of `item` can be stored at once.
+    pub fn with_stack_limit(mut self, item: impl Into<String>, limit: u32) -> Self {
+        self.stack_limits.insert(item.into(), limit);
+        self
+    }
+
+    /// Add up to `amount` of `item`, respecting its stack limit.
+    /// Returns how many were actually added.
+    pub fn add(&mut self, item: &str, amount: u32) -> u32 {
+        let limit = self.stack_limits.get(item).copied().unwrap_or(u32::MAX);
+        let count = self.items.entry(item.to_string()).or_default();
+        let added = amount.min(limit.saturating_sub(*count));
+        *count += added;
+        if *count == 0 {
+            self.items.remove(item);
+        }
+        added
+    }
+
+    /// Remove `amount` of `item` if there are enough.
+    /// Returns whether they were removed.
+    pub fn remove(&mut self, item: &str, amount: u32) -> bool {
+        let Some(count) = self.items.get_mut(item) else {
+            return amount == 0;
+        };
+        if *count < amount {
+            return false;
+        }
+        *count -= amount;
+        if *count == 0 {
+            self.items.remove(item);
+        }
+        true
+    }
+
+    /// How many of `item` are stored.
+    pub fn count(&self, item: &str) -> u32 {
+        self.items.get(item).copied().unwrap_or(0)
+    }
+
+    /// All stored items and their counts.
+    pub fn items(&self) -> impl Iterator<Item = (&str, u32)> {
+        self.items
+            .iter()
+            .map(|(item, count)| (item.as_str(), *count))
+    }
+}
+
+/// The contents of an entity's [`Inventory`] changed.
+/// Use this to refresh UI that shows the inventory.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct InventoryChanged {
+    pub entity: Entity,
+    pub item: String,
+}
+
+fn store_pickups(
+    mut collected_events: EventReader<PickupCollected>,
+    mut inventory_query: Query<&mut Inventory>,
+    mut changed_events: EventWriter<InventoryChanged>,
+) {
+    for event in collected_events.read() {
+        let Ok(mut inventory) = inventory_query.get_mut(event.collector) else {
+            continue;
+        };
+        if inventory.add(&event.kind, event.value) > 0 {
+            changed_events.send(InventoryChanged {
+                entity: event.collector,
+                item: event.kind.clone(),
+            });
+        }
+    }
+}
@@ -0,0 +1,126 @@
+//! Which keys trigger which player actions.
+//!
+//! [`KeyBindings`] is a [`Setting`], so rebinding a key persists it across
+//! launches. Rebind keys from the developer console with `bind <action> <key>`.
+
+use std::collections::BTreeMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::settings::{RegisterSetting as _, Setting, SettingsStore};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_setting::<KeyBindings>();
+}
+
+/// Something the player can do by pressing a key.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Action {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Fire,
+}
+
+impl Action {
+    pub const ALL: [Self; 5] = [
+        Self::MoveUp,
+        Self::MoveDown,
+        Self::MoveLeft,
+        Self::MoveRight,
+        Self::Fire,
+    ];
+
+    fn default_keys(self) -> Vec<KeyCode> {
+        match self {
+            Self::MoveUp => vec![KeyCode::KeyW, KeyCode::ArrowUp],
+            Self::MoveDown => vec![KeyCode::KeyS, KeyCode::ArrowDown],
+            Self::MoveLeft => vec![KeyCode::KeyA, KeyCode::ArrowLeft],
+            Self::MoveRight => vec![KeyCode::KeyD, KeyCode::ArrowRight],
+            Self::Fire => vec![KeyCode::Space],
+        }
+    }
+}
+
+/// The keys bound to each [`Action`]. Every action is bound to at least one key.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(
+    from = "BTreeMap<Action, Vec<KeyCode>>",
+    into = "BTreeMap<Action, Vec<KeyCode>>"
+)]
+pub struct KeyBindings(BTreeMap<Action, Vec<KeyCode>>);
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self(
+            Action::ALL
+                .into_iter()
+                .map(|action| (action, action.default_keys()))
+                .collect(),
+        )
+    }
+}
+
+impl From<BTreeMap<Action, Vec<KeyCode>>> for KeyBindings {
+    /// Fall back to the default keys for actions that are unbound,
+    /// so that a bad settings file can't make the game unplayable.
+    fn from(mut bindings: BTreeMap<Action, Vec<KeyCode>>) -> Self {
+        for action in Action::ALL {
+            let keys = bindings.entry(action).or_default();
+            if keys.is_empty() {
+                warn!("{action:?} is unbound, using the default keys");
+                *keys = action.default_keys();
+            }
+        }
+        Self(bindings)
+    }
+}
+
+impl From<KeyBindings> for BTreeMap<Action, Vec<KeyCode>> {
+    fn from(bindings: KeyBindings) -> Self {
+        bindings.0
+    }
+}
+
+impl Setting for KeyBindings {
+    const KEY: &'static str = "key_bindings";
+}
+
+impl KeyBindings {
+    /// The keys bound to `action`.
+    pub fn keys(&self, action: Action) -> &[KeyCode] {
+        self.0.get(&action).map_or(&[], Vec::as_slice)
+    }
+
+    /// Bind `action` to only `key`. Other actions bound to `key` keep it.
+    pub fn bind(&mut self, action: Action, key: KeyCode) {
+        self.0.insert(action, vec![key]);
+    }
+
+    pub fn pressed(&self, action: Action, input: &ButtonInput<KeyCode>) -> bool {
+        input.any_pressed(self.keys(action).iter().copied())
+    }
+
+    pub fn just_pressed(&self, action: Action, input: &ButtonInput<KeyCode>) -> bool {
+        input.any_just_pressed(self.keys(action).iter().copied())
+    }
+}
+
+/// A [`Command`](bevy::ecs::world::Command) that restores the default key
+/// bindings and immediately rewrites the stored settings.
+pub fn reset_key_bindings(world: &mut World) {
+    let bindings = KeyBindings::default();
+    let mut store = world.resource_mut::<SettingsStore>();
+    store.save(KeyBindings::KEY, &bindings);
+    store.flush();
+    world.insert_resource(bindings);
+}
+
+/// A run condition that is true when a key bound to `action` was just pressed.
+pub fn action_just_pressed(
+    action: Action,
+) -> impl FnMut(Res<KeyBindings>, Res<ButtonInput<KeyCode>>) -> bool + Clone {
+    move |bindings, input| bindings.just_pressed(action, &input)
+}
@@ -0,0 +1,163 @@
+//! Damage and knock back players that touch something dangerous.
+//!
+//! Add [`DamageOnContact`] to an entity with a [`Collider`] to hurt players
+//! that collide with it. The player is also pushed away from it with a
+//! [`Knockback`] that fades out over time, leaving a [`Trail`] if it has one.
+//!
+//! Note that colliders are pushed out of [`Solid`](crate::demo::collision::Solid)s
+//! before collisions are detected, so they only touch and never overlap. To
+//! make a dangerous wall, give it a non-solid collider instead.
+
+use bevy::prelude::*;
+
+use crate::{
+    demo::{
+        collision::{detect_collisions, resolve_solid_collisions, Collider, CollisionEvent},
+        health::{apply_damage, DamageEvent, Invulnerability},
+        movement::{apply_movement, PhysicalTransform},
+        player::Player,
+        trail::Trail,
+    },
+    AppSet,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<(DamageOnContact, Knockback)>();
+    app.add_systems(
+        Update,
+        (
+            // Knockback is applied like regular movement, so it is blocked by walls.
+            apply_knockback
+                .after(apply_movement)
+                .before(resolve_solid_collisions),
+            damage_on_contact
+                .after(detect_collisions)
+                .before(apply_damage),
+            show_knockback_trail,
+        )
+            .in_set(AppSet::Update),
+    );
+    app.observe(start_knockback);
+}
+
+/// Damage players that collide with this entity and knock them back.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+pub struct DamageOnContact {
+    pub amount: f32,
+    /// The initial knockback speed in world units per second.
+    pub knockback: f32,
+}
+
+/// An impulse pushing an entity, which fades out over time.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Default)]
+#[reflect(Component)]
+pub struct Knockback {
+    /// The current velocity in world units per second.
+    pub velocity: Vec2,
+}
+
+/// Knock an entity back. Trigger this on the entity to push.
+#[derive(Event, Debug, Clone, Copy, PartialEq)]
+pub struct KnockbackEvent {
+    /// The velocity to add in world units per second.
+    pub impulse: Vec2,
+}
+
+/// How quickly knockback fades out. Higher is faster.
+const KNOCKBACK_DAMPING: f32 = 8.0;
+
+/// Knockback slower than this is removed.
+const MIN_KNOCKBACK_SPEED: f32 = 10.0;
+
+fn damage_on_contact(
+    mut commands: Commands,
+    mut collision_events: EventReader<CollisionEvent>,
+    damager_query: Query<(&DamageOnContact, &Transform)>,
+    player_query: Query<(&Transform, Option<&Invulnerability>), With<Player>>,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    for event in collision_events.read() {
+        for (damager, target) in [(event.a, event.b), (event.b, event.a)] {
+            let Ok((damage, damager_transform)) = damager_query.get(damager) else {
+                continue;
+            };
+            let Ok((target_transform, invulnerability)) = player_query.get(target) else {
+                continue;
+            };
+            // Don't keep pushing players that were just hit.
+            if invulnerability.is_some_and(Invulnerability::is_active) {
+                continue;
+            }
+
+            damage_events.send(DamageEvent {
+                target,
+                amount: damage.amount,
+            });
+            let direction = (target_transform.translation.xy()
+                - damager_transform.translation.xy())
+            .try_normalize()
+            .unwrap_or(Vec2::Y);
+            commands.trigger_targets(
+                KnockbackEvent {
+                    impulse: direction * damage.knockback,
+                },
+                target,
+            );
+        }
+    }
+}
+
+fn start_knockback(
+    trigger: Trigger<KnockbackEvent>,
+    mut commands: Commands,
+    mut knockback_query: Query<&mut Knockback>,
+) {
+    let entity = trigger.entity();
+    let impulse = trigger.event().impulse;
+    match knockback_query.get_mut(entity) {
+        Ok(mut knockback) => knockback.velocity += impulse,
+        Err(_) => {
+            commands
+                .entity(entity)
+                .insert(Knockback { velocity: impulse });
+        }
+    }
+}
+
+fn apply_knockback(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut knockback_query: Query<(
+        Entity,
+        &mut Knockback,
+        &mut Transform,
+        Option<&mut PhysicalTransform>,
+    )>,
+) {
+    let dt = time.delta_seconds();
+    for (entity, mut knockback, mut transform, physical) in &mut knockback_query {
+        let offset = (knockback.velocity * dt).extend(0.0);
+        match physical {
+            Some(mut physical) => {
+                physical.translation += offset;
+                physical.previous_translation += offset;
+            }
+            None => transform.translation += offset,
+        }
+
+        knockback.velocity *= (-KNOCKBACK_DAMPING * dt).exp();
+        if knockback.velocity.length() < MIN_KNOCKBACK_SPEED {
+            commands.entity(entity).remove::<Knockback>();
+        }
+    }
+}
+
+fn show_knockback_trail(mut trail_query: Query<(&mut Trail, Has<Knockback>)>) {
+    for (mut trail, knocked_back) in &mut trail_query {
+        // Avoid triggering change detection if nothing changed.
+        if trail.active != knocked_back {
+            trail.active = knocked_back;
+        }
+    }
+}
@@ -1,20 +1,320 @@
-//! Spawn the main level.
+//! Spawn the main level from a map file.
+//!
+//! A map is a grid of symbols, e.g. `#` for a wall, which is loaded from
+//! a RON file in `assets/levels`. Each symbol is spawned by the function
+//! registered for it with [`RegisterTile::register_tile`].
 
-use bevy::{ecs::world::Command, prelude::*};
+use std::time::Duration;
 
-use crate::demo::player::SpawnPlayer;
+use bevy::{ecs::world::Command, prelude::*, utils::HashMap};
+use serde::Deserialize;
 
-pub(super) fn plugin(_app: &mut App) {
-    // No setup required for this plugin.
-    // It's still good to have a function here so that we can add some setup
-    // later if needed.
+use crate::{
+    asset_tracking::{LoadResource, RonLoader},
+    config::GameConfig,
+    demo::{
+        collision::{Collider, Solid},
+        enemy::SpawnEnemy,
+        level_timer::{LevelTimer, TimeOut},
+        parallax::spawn_parallax_background,
+        pickup::{Pickup, SpawnPickup, HEAL, TIME_BONUS, TIME_PENALTY},
+        player::SpawnPlayer,
+        respawn::{RespawnPoint, SpawnCheckpoint},
+        run_stats::RunStats,
+    },
+    screens::Screen,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_asset::<LevelMap>();
+    app.register_asset_loader(RonLoader::<LevelMap>::new(&["ron"]));
+    app.load_resource::<LevelAssets>();
+
+    app.register_tile('.', spawn_floor)
+        .register_tile('#', spawn_wall)
+        .register_tile('P', spawn_player_start)
+        .register_tile('E', spawn_enemy_start)
+        .register_tile('C', spawn_coin)
+        .register_tile('T', spawn_time_bonus)
+        .register_tile('X', spawn_time_penalty)
+        .register_tile('H', spawn_heal)
+        .register_tile('K', spawn_checkpoint);
+}
+
+/// A grid of tile symbols.
+#[derive(Asset, Reflect, Deserialize, Debug, Clone)]
+pub struct LevelMap {
+    /// The width and height of a tile in world units.
+    pub tile_size: f32,
+    /// The time in seconds to finish the level, if it has a time limit.
+    #[serde(default)]
+    pub time_limit: Option<f32>,
+    /// What happens when the time limit runs out.
+    #[serde(default)]
+    pub on_time_out: TimeOut,
+    /// The rows of the map from top to bottom, one symbol per tile.
+    /// Spaces are left empty.
+    pub rows: Vec<String>,
+}
+
+impl LevelMap {
+    /// The area covered by the map in world units, centered on the origin.
+    pub fn bounds(&self) -> Rect {
+        let width = self
+            .rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+        let size = Vec2::new(width as f32, self.rows.len() as f32) * self.tile_size;
+        Rect::from_center_size(Vec2::ZERO, size)
+    }
+
+    /// The world positions of every symbol in the map, centered on the origin.
+    pub fn tiles(&self) -> impl Iterator<Item = (char, Tile)> + '_ {
+        let height = self.rows.len();
+        let width = self
+            .rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+        let offset = Vec2::new(width as f32 - 1.0, height as f32 - 1.0) / 2.0;
+
+        self.rows.iter().enumerate().flat_map(move |(y, row)| {
+            row.chars().enumerate().map(move |(x, symbol)| {
+                // Rows go from top to bottom, but world space y goes up.
+                let grid = Vec2::new(x as f32, -(y as f32)) + Vec2::new(-offset.x, offset.y);
+                let tile = Tile {
+                    position: grid * self.tile_size,
+                    size: self.tile_size,
+                };
+                (symbol, tile)
+            })
+        })
+    }
+}
+
+#[derive(Resource, Asset, Reflect, Clone)]
+pub struct LevelAssets {
+    #[dependency]
+    pub map: Handle<LevelMap>,
+}
+
+impl LevelAssets {
+    pub const PATH_MAP: &'static str = "levels/level1.ron";
+}
+
+impl FromWorld for LevelAssets {
+    fn from_world(world: &mut World) -> Self {
+        let assets = world.resource::<AssetServer>();
+        Self {
+            map: assets.load(LevelAssets::PATH_MAP),
+        }
+    }
+}
+
+/// The area covered by the current level.
+/// Anything that leaves it, such as a projectile, can be despawned.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct WorldBounds(pub Rect);
+
+/// Where and how big a tile is.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tile {
+    /// The center of the tile in world units.
+    pub position: Vec2,
+    /// The width and height of the tile in world units.
+    pub size: f32,
+}
+
+/// A function that spawns the entities for one tile.
+pub type SpawnTile = fn(&mut World, Tile);
+
+/// The spawn functions for each map symbol.
+#[derive(Resource, Default)]
+pub struct TileRegistry(HashMap<char, SpawnTile>);
+
+pub trait RegisterTile {
+    /// Spawn tiles with the given symbol using `spawn`.
+    /// Registering a symbol again replaces its spawn function.
+    fn register_tile(&mut self, symbol: char, spawn: SpawnTile) -> &mut Self;
+}
+
+impl RegisterTile for App {
+    fn register_tile(&mut self, symbol: char, spawn: SpawnTile) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_insert_with(TileRegistry::default)
+            .0
+            .insert(symbol, spawn);
+        self
+    }
 }
 
 /// A [`Command`] to spawn the level.
 /// Functions that accept only `&mut World` as their parameter implement [`Command`].
 /// We use this style when a command requires no configuration.
 pub fn spawn_level(world: &mut World) {
-    // The only thing we have in our level is a player,
-    // but add things like walls etc. here.
-    SpawnPlayer { max_speed: 400.0 }.apply(world);
+    let level_assets = world.resource::<LevelAssets>();
+    let Some(map) = world
+        .resource::<Assets<LevelMap>>()
+        .get(&level_assets.map)
+        .cloned()
+    else {
+        error!("Level map {} is not loaded", LevelAssets::PATH_MAP);
+        return;
+    };
+
+    world.resource_mut::<RunStats>().reset();
+    world.insert_resource(WorldBounds(map.bounds()));
+    match map.time_limit {
+        Some(time_limit) => world.insert_resource(LevelTimer::new(
+            Duration::from_secs_f32(time_limit),
+            map.on_time_out,
+        )),
+        None => {
+            world.remove_resource::<LevelTimer>();
+        }
+    }
+    spawn_parallax_background(world);
+    for (symbol, tile) in map.tiles() {
+        if symbol == ' ' {
+            continue;
+        }
+        let spawn = world.resource::<TileRegistry>().0.get(&symbol).copied();
+        match spawn {
+            Some(spawn) => spawn(world, tile),
+            None => warn!("No tile registered for symbol `{symbol}`"),
+        }
+    }
+}
+
+const FLOOR_COLOR: Color = Color::srgb(0.22, 0.22, 0.22);
+const WALL_COLOR: Color = Color::srgb(0.45, 0.4, 0.35);
+
+fn spawn_floor(world: &mut World, tile: Tile) {
+    world.spawn((
+        Name::new("Floor"),
+        SpriteBundle {
+            sprite: Sprite {
+                color: FLOOR_COLOR,
+                custom_size: Some(Vec2::splat(tile.size)),
+                ..default()
+            },
+            transform: Transform::from_translation(tile.position.extend(-2.0)),
+            ..default()
+        },
+        StateScoped(Screen::Gameplay),
+    ));
+}
+
+fn spawn_wall(world: &mut World, tile: Tile) {
+    world.spawn((
+        Name::new("Wall"),
+        SpriteBundle {
+            sprite: Sprite {
+                color: WALL_COLOR,
+                custom_size: Some(Vec2::splat(tile.size)),
+                ..default()
+            },
+            transform: Transform::from_translation(tile.position.extend(-1.0)),
+            ..default()
+        },
+        Collider::new(Vec2::splat(tile.size / 2.0)),
+        Solid,
+        StateScoped(Screen::Gameplay),
+    ));
+}
+
+fn spawn_player_start(world: &mut World, tile: Tile) {
+    spawn_floor(world, tile);
+    world.insert_resource(RespawnPoint(tile.position));
+    let max_speed = world.resource::<GameConfig>().player.max_speed;
+    SpawnPlayer {
+        max_speed,
+        position: tile.position,
+    }
+    .apply(world);
+}
+
+/// How many tiles enemies patrol from their start toward the middle of the level.
+const ENEMY_PATROL_TILES: f32 = 3.0;
+
+fn spawn_enemy_start(world: &mut World, tile: Tile) {
+    spawn_floor(world, tile);
+    let toward_center = -tile.position.x.signum();
+    let patrol_end = tile.position + Vec2::X * toward_center * ENEMY_PATROL_TILES * tile.size;
+    SpawnEnemy {
+        speed: 150.0,
+        position: tile.position,
+        patrol: vec![tile.position, patrol_end],
+    }
+    .apply(world);
+}
+
+fn spawn_coin(world: &mut World, tile: Tile) {
+    spawn_floor(world, tile);
+    SpawnPickup {
+        pickup: Pickup {
+            kind: "coin".to_string(),
+            value: 1,
+        },
+        position: tile.position,
+        drop_height: 0.0,
+    }
+    .apply(world);
+}
+
+/// Seconds added to or subtracted from the [`LevelTimer`] by time pickups.
+const TIME_PICKUP_SECONDS: u32 = 10;
+
+fn spawn_time_bonus(world: &mut World, tile: Tile) {
+    spawn_floor(world, tile);
+    SpawnPickup {
+        pickup: Pickup {
+            kind: TIME_BONUS.to_string(),
+            value: TIME_PICKUP_SECONDS,
+        },
+        position: tile.position,
+        drop_height: 0.0,
+    }
+    .apply(world);
+}
+
+fn spawn_time_penalty(world: &mut World, tile: Tile) {
+    spawn_floor(world, tile);
+    SpawnPickup {
+        pickup: Pickup {
+            kind: TIME_PENALTY.to_string(),
+            value: TIME_PICKUP_SECONDS,
+        },
+        position: tile.position,
+        drop_height: 0.0,
+    }
+    .apply(world);
+}
+
+/// How much health heal pickups restore.
+const HEAL_AMOUNT: u32 = 25;
+
+fn spawn_heal(world: &mut World, tile: Tile) {
+    spawn_floor(world, tile);
+    SpawnPickup {
+        pickup: Pickup {
+            kind: HEAL.to_string(),
+            value: HEAL_AMOUNT,
+        },
+        position: tile.position,
+        drop_height: 0.0,
+    }
+    .apply(world);
+}
+
+fn spawn_checkpoint(world: &mut World, tile: Tile) {
+    spawn_floor(world, tile);
+    SpawnCheckpoint {
+        position: tile.position,
+        size: tile.size,
+    }
+    .apply(world);
 }
@@ -0,0 +1,191 @@
+//! A countdown for time-attack levels.
+//!
+//! Levels with a `time_limit` get a [`LevelTimer`] that counts down during
+//! gameplay. When it runs out, it sends [`LevelComplete`] or kills the player,
+//! depending on the level's `on_time_out`, and the run ends by returning to
+//! the title screen.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    demo::{health::DeathEvent, player::Player},
+    screens::Screen,
+    theme::prelude::*,
+    AppSet,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<LevelTimer>();
+    app.add_event::<LevelComplete>();
+    app.add_systems(OnEnter(Screen::Gameplay), spawn_level_timer_text);
+    app.add_systems(OnExit(Screen::Gameplay), remove_level_timer);
+    app.add_systems(
+        Update,
+        (
+            tick_level_timer
+                .in_set(AppSet::TickTimers)
+                .run_if(in_state(Screen::Gameplay)),
+            (check_level_timer, update_level_timer_text)
+                .chain()
+                .in_set(AppSet::Update),
+        )
+            .run_if(resource_exists::<LevelTimer>),
+    );
+}
+
+/// What happens when the [`LevelTimer`] runs out.
+#[derive(Reflect, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeOut {
+    /// Send [`LevelComplete`], e.g. for survival levels.
+    LevelComplete,
+    /// Trigger a [`DeathEvent`] on every player.
+    #[default]
+    PlayerDeath,
+}
+
+/// Counts down the time left in the current level.
+#[derive(Resource, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Resource)]
+pub struct LevelTimer {
+    timer: Timer,
+    pub on_time_out: TimeOut,
+}
+
+impl LevelTimer {
+    pub fn new(time_limit: Duration, on_time_out: TimeOut) -> Self {
+        Self {
+            timer: Timer::new(time_limit, TimerMode::Once),
+            on_time_out,
+        }
+    }
+
+    pub fn remaining(&self) -> Duration {
+        self.timer.remaining()
+    }
+
+    /// Extend the time left, e.g. when collecting a pickup.
+    /// This restarts the countdown if it already ran out.
+    pub fn add(&mut self, time: Duration) {
+        if time.is_zero() {
+            return;
+        }
+        let elapsed = self.timer.elapsed();
+        // Resetting clears the finished state of the timer.
+        self.timer.reset();
+        self.timer.set_elapsed(elapsed.saturating_sub(time));
+    }
+
+    /// Shorten the time left, e.g. as a penalty. The time left stops at zero.
+    pub fn subtract(&mut self, time: Duration) {
+        let elapsed = self.timer.elapsed();
+        self.timer
+            .set_elapsed((elapsed + time).min(self.timer.duration()));
+    }
+}
+
+/// The current level was completed.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LevelComplete;
+
+fn tick_level_timer(time: Res<Time>, mut level_timer: ResMut<LevelTimer>) {
+    level_timer.timer.tick(time.delta());
+}
+
+fn check_level_timer(
+    mut commands: Commands,
+    level_timer: Res<LevelTimer>,
+    player_query: Query<Entity, With<Player>>,
+    mut complete_events: EventWriter<LevelComplete>,
+    mut death_events: EventWriter<DeathEvent>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    if !level_timer.timer.just_finished() {
+        return;
+    }
+    match level_timer.on_time_out {
+        TimeOut::LevelComplete => {
+            complete_events.send(LevelComplete);
+        }
+        TimeOut::PlayerDeath => {
+            for entity in &player_query {
+                let event = DeathEvent { entity };
+                death_events.send(event);
+                commands.trigger_targets(event, entity);
+            }
+        }
+    }
+    // End the run, so that e.g. respawning doesn't continue it without time left.
+    next_screen.set(Screen::Title);
+}
+
+fn remove_level_timer(mut commands: Commands) {
+    commands.remove_resource::<LevelTimer>();
+}
+
+#[derive(Component)]
+struct LevelTimerText;
+
+fn spawn_level_timer_text(mut commands: Commands) {
+    commands
+        .spawn((
+            Name::new("Level Timer"),
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    top: Val::Px(16.0),
+                    justify_content: JustifyContent::Center,
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                ..default()
+            },
+            StateScoped(Screen::Gameplay),
+        ))
+        .with_children(|children| {
+            children.label("").insert(LevelTimerText);
+        });
+}
+
+fn update_level_timer_text(
+    level_timer: Res<LevelTimer>,
+    mut text_query: Query<&mut Text, With<LevelTimerText>>,
+) {
+    let remaining = level_timer.remaining().as_secs_f32().ceil() as u32;
+    let value = format!("{}:{:02}", remaining / 60, remaining % 60);
+    for mut text in &mut text_query {
+        if text.sections[0].value != value {
+            text.sections[0].value.clone_from(&value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn subtract_stops_at_zero() {
+        let mut level_timer = LevelTimer::new(Duration::from_secs(5), TimeOut::default());
+        level_timer.subtract(Duration::from_secs(10));
+        assert_eq!(level_timer.remaining(), Duration::ZERO);
+
+        // Subtracting after the timer ran out must not underflow either.
+        level_timer.timer.tick(Duration::from_secs(1));
+        level_timer.subtract(Duration::from_secs(10));
+        assert_eq!(level_timer.remaining(), Duration::ZERO);
+    }
+
+    #[test]
+    fn add_restarts_finished_timer() {
+        let mut level_timer = LevelTimer::new(Duration::from_secs(5), TimeOut::default());
+        level_timer.timer.tick(Duration::from_secs(5));
+        assert!(level_timer.timer.finished());
+
+        level_timer.add(Duration::from_secs(3));
+        assert!(!level_timer.timer.finished());
+        assert_eq!(level_timer.remaining(), Duration::from_secs(3));
+    }
+}
@@ -0,0 +1,60 @@
+//! Check whether anything [`Solid`] blocks the view between two points.
+//!
+//! Use the [`LineOfSight`] system param, e.g. to only let enemies chase
+//! players they can see.
+
+use bevy::{ecs::system::SystemParam, prelude::*};
+
+use crate::demo::{
+    collision::{Collider, Solid},
+    spatial_grid::SpatialGrid,
+};
+
+/// Looks for [`Solid`] colliders between two points.
+///
+/// Candidates are found with the [`SpatialGrid`], so this sees the solids
+/// where they were when the grid was last updated.
+#[derive(SystemParam)]
+pub struct LineOfSight<'w, 's> {
+    grid: Res<'w, SpatialGrid>,
+    solid_query: Query<'w, 's, (&'static Collider, &'static Transform), With<Solid>>,
+}
+
+impl LineOfSight<'_, '_> {
+    /// Whether the straight line from `a` to `b` doesn't pass through any [`Solid`].
+    /// Lines that only graze the edge of a solid are not blocked.
+    pub fn has_line_of_sight(&self, a: Vec2, b: Vec2) -> bool {
+        !self.grid.query_segment(a, b).any(|entity| {
+            self.solid_query
+                .get(entity)
+                .is_ok_and(|(collider, transform)| {
+                    segment_intersects(a, b, collider.aabb(transform.translation.xy()))
+                })
+        })
+    }
+}
+
+/// Whether the line segment from `start` to `end` passes through the inside of `rect`.
+fn segment_intersects(start: Vec2, end: Vec2, rect: Rect) -> bool {
+    let delta = end - start;
+    // The part of the segment inside `rect`, as fractions of the segment.
+    let mut t_min = 0.0_f32;
+    let mut t_max = 1.0_f32;
+    for axis in 0..2 {
+        if delta[axis] == 0.0 {
+            // Parallel to this axis, so it's either always or never inside.
+            if start[axis] <= rect.min[axis] || start[axis] >= rect.max[axis] {
+                return false;
+            }
+            continue;
+        }
+        let t1 = (rect.min[axis] - start[axis]) / delta[axis];
+        let t2 = (rect.max[axis] - start[axis]) / delta[axis];
+        t_min = t_min.max(t1.min(t2));
+        t_max = t_max.min(t1.max(t2));
+        if t_min >= t_max {
+            return false;
+        }
+    }
+    true
+}
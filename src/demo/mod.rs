@@ -5,16 +5,63 @@
 
 use bevy::prelude::*;
 
-mod animation;
+pub mod animation;
+pub mod collision;
+pub mod damage_flash;
+pub mod enemy;
+pub mod gravity;
+pub mod grid_movement;
+pub mod health;
+pub mod inventory;
+pub mod key_bindings;
+pub mod knockback;
 pub mod level;
-mod movement;
+pub mod level_timer;
+pub mod line_of_sight;
+pub mod movement;
+pub mod parallax;
+pub mod patrol;
+pub mod pickup;
 pub mod player;
+pub mod playtime;
+pub mod projectile;
+pub mod respawn;
+pub mod run_stats;
+pub mod sensor;
+pub mod spatial_grid;
+pub mod sprite_variant;
+pub mod squash_stretch;
+pub mod trail;
 
 pub(super) fn plugin(app: &mut App) {
     app.add_plugins((
         animation::plugin,
+        collision::plugin,
+        enemy::plugin,
+        gravity::plugin,
+        health::plugin,
+        inventory::plugin,
+        knockback::plugin,
         movement::plugin,
+        pickup::plugin,
         player::plugin,
+        projectile::plugin,
+        respawn::plugin,
+        run_stats::plugin,
+        sensor::plugin,
+        spatial_grid::plugin,
+    ));
+    app.add_plugins((
+        damage_flash::plugin,
+        grid_movement::plugin,
+        key_bindings::plugin,
         level::plugin,
+        level_timer::plugin,
+        parallax::plugin,
+        patrol::plugin,
+        playtime::plugin,
+        sprite_variant::plugin,
+        squash_stretch::plugin,
+        trail::plugin,
     ));
 }
@@ -7,25 +7,34 @@
 //!   This is done in the `player` module, as it is specific to the player
 //!   character.
 //! - Apply movement based on [`MovementController`] intent and maximum speed.
+//! - Remember the last direction moved in as the [`Facing`].
 //! - Wrap the character within the window.
 //!
 //! Note that the implementation used here is limited for demonstration
-//! purposes. If you want to move the player in a smoother way,
-//! consider using a [fixed timestep](https://github.com/bevyengine/bevy/blob/main/examples/movement/physics_in_fixed_timestep.rs).
+//! purposes. By default, movement is applied once per frame in `Update`.
+//! Add a [`PhysicalTransform`] to opt into moving in `FixedUpdate` instead,
+//! which is based on the [fixed timestep example](https://github.com/bevyengine/bevy/blob/main/examples/movement/physics_in_fixed_timestep.rs).
+//! Add a [`GridMovement`] to move one tile at a time instead.
 
 use bevy::{prelude::*, window::PrimaryWindow};
 
-use crate::AppSet;
+use crate::{demo::grid_movement::GridMovement, AppSet};
 
 pub(super) fn plugin(app: &mut App) {
-    app.register_type::<(MovementController, ScreenWrap)>();
+    app.register_type::<(MovementController, Facing, PhysicalTransform, ScreenWrap)>();
 
     app.add_systems(
         Update,
-        (apply_movement, apply_screen_wrap)
+        (
+            update_facing,
+            apply_movement,
+            apply_screen_wrap,
+            interpolate_physical_transforms,
+        )
             .chain()
             .in_set(AppSet::Update),
     );
+    app.add_systems(FixedUpdate, apply_fixed_movement.in_set(AppSet::Update));
 }
 
 /// These are the movement parameters for our character controller.
@@ -53,9 +62,62 @@ impl Default for MovementController {
     }
 }
 
-fn apply_movement(
+/// The direction a character last moved in. It doesn't change while standing still.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Component)]
+pub enum Facing {
+    Left,
+    #[default]
+    Right,
+    Up,
+    Down,
+}
+
+impl Facing {
+    /// The cardinal direction closest to `direction`, or `None` for zero.
+    pub fn from_direction(direction: Vec2) -> Option<Self> {
+        if direction == Vec2::ZERO {
+            None
+        } else if direction.x.abs() >= direction.y.abs() {
+            Some(if direction.x < 0.0 {
+                Self::Left
+            } else {
+                Self::Right
+            })
+        } else {
+            Some(if direction.y < 0.0 {
+                Self::Down
+            } else {
+                Self::Up
+            })
+        }
+    }
+
+    pub fn direction(self) -> Vec2 {
+        match self {
+            Self::Left => Vec2::NEG_X,
+            Self::Right => Vec2::X,
+            Self::Up => Vec2::Y,
+            Self::Down => Vec2::NEG_Y,
+        }
+    }
+}
+
+pub(super) fn update_facing(mut facing_query: Query<(&MovementController, &mut Facing)>) {
+    for (controller, mut facing) in &mut facing_query {
+        if let Some(new_facing) = Facing::from_direction(controller.intent) {
+            // Avoid triggering change detection if the facing stays the same.
+            facing.set_if_neq(new_facing);
+        }
+    }
+}
+
+pub(super) fn apply_movement(
     time: Res<Time>,
-    mut movement_query: Query<(&MovementController, &mut Transform)>,
+    mut movement_query: Query<
+        (&MovementController, &mut Transform),
+        (Without<PhysicalTransform>, Without<GridMovement>),
+    >,
 ) {
     for (controller, mut transform) in &mut movement_query {
         let velocity = controller.max_speed * controller.intent;
@@ -63,20 +125,83 @@ fn apply_movement(
     }
 }
 
+/// Opts a [`MovementController`] into moving in `FixedUpdate`.
+///
+/// The fixed timestep makes movement independent of the frame rate. Since the
+/// fixed timestep rarely lines up with rendered frames, the visual [`Transform`]
+/// is interpolated between the last two fixed timesteps to avoid jitter.
+///
+/// Note that [`Solid`](crate::demo::collision::Solid) colliders don't block
+/// entities moving in `FixedUpdate`.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Default)]
+#[reflect(Component)]
+pub struct PhysicalTransform {
+    /// The translation after the most recent fixed timestep.
+    pub translation: Vec3,
+    /// The translation after the fixed timestep before that.
+    pub previous_translation: Vec3,
+}
+
+impl PhysicalTransform {
+    pub fn from_translation(translation: Vec3) -> Self {
+        Self {
+            translation,
+            previous_translation: translation,
+        }
+    }
+}
+
+pub(super) fn apply_fixed_movement(
+    time: Res<Time>,
+    mut movement_query: Query<(&MovementController, &mut PhysicalTransform), Without<GridMovement>>,
+) {
+    for (controller, mut physical) in &mut movement_query {
+        let velocity = controller.max_speed * controller.intent;
+        physical.previous_translation = physical.translation;
+        physical.translation += velocity.extend(0.0) * time.delta_seconds();
+    }
+}
+
+/// Place the visual [`Transform`] between the last two fixed timesteps,
+/// based on how far we are into the next one.
+fn interpolate_physical_transforms(
+    fixed_time: Res<Time<Fixed>>,
+    mut physical_query: Query<(&PhysicalTransform, &mut Transform)>,
+) {
+    let alpha = fixed_time.overstep_fraction();
+    for (physical, mut transform) in &mut physical_query {
+        transform.translation = physical
+            .previous_translation
+            .lerp(physical.translation, alpha);
+    }
+}
+
 #[derive(Component, Reflect)]
 #[reflect(Component)]
 pub struct ScreenWrap;
 
-fn apply_screen_wrap(
+pub(super) fn apply_screen_wrap(
     window_query: Query<&Window, With<PrimaryWindow>>,
-    mut wrap_query: Query<&mut Transform, With<ScreenWrap>>,
+    mut wrap_query: Query<(&mut Transform, Option<&mut PhysicalTransform>), With<ScreenWrap>>,
 ) {
     let Ok(window) = window_query.get_single() else {
         return;
     };
     let size = window.size() + 256.0;
     let half_size = size / 2.0;
-    for mut transform in &mut wrap_query {
+    for (mut transform, physical) in &mut wrap_query {
+        if let Some(mut physical) = physical {
+            // Shift both physical translations so that the interpolation
+            // doesn't sweep across the screen.
+            let position = physical.translation.xy();
+            let wrapped = (position + half_size).rem_euclid(size) - half_size;
+            let offset = (wrapped - position).extend(0.0);
+            if offset != Vec3::ZERO {
+                physical.translation += offset;
+                physical.previous_translation += offset;
+            }
+            continue;
+        }
         let position = transform.translation.xy();
         let wrapped = (position + half_size).rem_euclid(size) - half_size;
         transform.translation = wrapped.extend(transform.translation.z);
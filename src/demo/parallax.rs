@@ -0,0 +1,145 @@
+//! Background layers that scroll slower than the level to give it depth.
+//!
+//! Each [`ParallaxLayer`] follows the camera by its [`ParallaxLayer::factor`]
+//! and repeats its texture to always cover the viewport. With
+//! [`AccessibilitySettings::reduced_motion`], the layers move like the level.
+
+use bevy::{
+    prelude::*,
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        texture::ImageSampler,
+    },
+    sprite::ImageScaleMode,
+    transform::TransformSystem,
+};
+
+use crate::{accessibility::AccessibilitySettings, screens::Screen};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<ParallaxLayer>();
+    // Run after the camera has moved for this frame.
+    app.add_systems(
+        PostUpdate,
+        update_parallax_layers.before(TransformSystem::TransformPropagate),
+    );
+}
+
+/// A repeating background that moves with the camera by [`Self::factor`].
+/// The entity needs a [`Sprite`] whose texture tiles every [`Self::tile_size`].
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+pub struct ParallaxLayer {
+    /// How much the layer follows the camera. 0 moves like the level,
+    /// and values closer to 1 look further away.
+    pub factor: f32,
+    /// The size of one repetition of the texture in world units.
+    pub tile_size: Vec2,
+}
+
+fn update_parallax_layers(
+    accessibility: Res<AccessibilitySettings>,
+    camera_query: Query<(&Transform, &OrthographicProjection), With<Camera2d>>,
+    mut layer_query: Query<(&ParallaxLayer, &mut Transform, &mut Sprite), Without<Camera2d>>,
+) {
+    let Ok((camera_transform, projection)) = camera_query.get_single() else {
+        return;
+    };
+    let camera = camera_transform.translation.xy();
+    let viewport = projection.area.size();
+
+    for (layer, mut transform, mut sprite) in &mut layer_query {
+        // Moving at a different speed than the level can cause motion sickness.
+        let factor = if accessibility.reduced_motion {
+            0.0
+        } else {
+            layer.factor
+        };
+        let position = camera * factor;
+        // Jump by whole tiles to stay centered on the camera. The texture
+        // repeats, so the jump isn't visible.
+        let jump = ((camera - position) / layer.tile_size).round() * layer.tile_size;
+        transform.translation = (position + jump).extend(transform.translation.z);
+
+        // Cover the viewport with a tile to spare on each side.
+        let size = viewport + 2.0 * layer.tile_size;
+        if sprite.custom_size != Some(size) {
+            sprite.custom_size = Some(size);
+        }
+    }
+}
+
+/// The background layers from back to front: `(factor, tile size, star color)`.
+const LAYERS: [(f32, u32, Color); 2] = [
+    (0.8, 128, Color::srgb(0.35, 0.35, 0.45)),
+    (0.5, 96, Color::srgb(0.6, 0.6, 0.7)),
+];
+
+/// Positions of the stars within a tile, as fractions of its size.
+const STARS: [(f32, f32); 5] = [
+    (0.1, 0.2),
+    (0.45, 0.8),
+    (0.7, 0.35),
+    (0.9, 0.9),
+    (0.3, 0.55),
+];
+
+/// Draw the background behind the level.
+const BACKGROUND_Z: f32 = -10.0;
+
+/// Upscale the star textures so that each texel covers several pixels.
+const TEXEL_SIZE: u32 = 4;
+
+/// A [`Command`](bevy::ecs::world::Command) that spawns a starry parallax background.
+pub fn spawn_parallax_background(world: &mut World) {
+    for (i, (factor, tile_size, color)) in LAYERS.into_iter().enumerate() {
+        let image = star_tile(tile_size / TEXEL_SIZE, color);
+        let texture = world.resource_mut::<Assets<Image>>().add(image);
+        world.spawn((
+            Name::new("Parallax Layer"),
+            ParallaxLayer {
+                factor,
+                tile_size: Vec2::splat(tile_size as f32),
+            },
+            SpriteBundle {
+                texture,
+                transform: Transform::from_xyz(0.0, 0.0, BACKGROUND_Z + i as f32),
+                ..default()
+            },
+            ImageScaleMode::Tiled {
+                tile_x: true,
+                tile_y: true,
+                stretch_value: TEXEL_SIZE as f32,
+            },
+            StateScoped(Screen::Gameplay),
+        ));
+    }
+}
+
+/// A transparent square texture with a few stars in it.
+fn star_tile(size: u32, color: Color) -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+    // Keep the stars crisp when the texture is scaled up.
+    image.sampler = ImageSampler::nearest();
+
+    let color = color.to_srgba();
+    let pixel = [color.red, color.green, color.blue, color.alpha].map(|c| (c * 255.0) as u8);
+    for (x, y) in STARS {
+        let x = (x * size as f32) as u32 % size;
+        let y = (y * size as f32) as u32 % size;
+        let offset = ((y * size + x) * 4) as usize;
+        image.data[offset..offset + 4].copy_from_slice(&pixel);
+    }
+    image
+}
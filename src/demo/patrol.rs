@@ -0,0 +1,109 @@
+//! Move entities back and forth along a path, e.g. for moving platforms or
+//! patrolling enemies.
+//!
+//! Like [`ChasePlayer`](crate::demo::enemy::ChasePlayer), [`Patrol`] only sets
+//! the [`MovementController`] intent, so the actual movement composes with
+//! collisions and any other system that moves the entity.
+
+use bevy::prelude::*;
+
+use crate::{
+    demo::movement::{apply_movement, MovementController},
+    AppSet,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Patrol>();
+    app.add_systems(
+        Update,
+        follow_patrol.before(apply_movement).in_set(AppSet::Update),
+    );
+}
+
+/// Move along a list of waypoints.
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component)]
+pub struct Patrol {
+    /// The waypoints in world space. They can be changed at any time.
+    pub points: Vec<Vec2>,
+    /// The speed in world units per second.
+    /// This overrides [`MovementController::max_speed`].
+    pub speed: f32,
+    /// Whether to go from the last waypoint back to the first one,
+    /// instead of walking the path in reverse.
+    pub looping: bool,
+    /// The index of the waypoint currently moved toward.
+    target: usize,
+    /// Whether the path is walked in reverse right now.
+    reversed: bool,
+}
+
+impl Patrol {
+    pub fn new(points: Vec<Vec2>, speed: f32, looping: bool) -> Self {
+        Self {
+            points,
+            speed,
+            looping,
+            target: 0,
+            reversed: false,
+        }
+    }
+
+    /// The waypoint currently moved toward, if there are any.
+    pub fn target(&self) -> Option<Vec2> {
+        self.points.get(self.target).copied()
+    }
+
+    /// Move on to the next waypoint, turning around or looping at the end.
+    fn advance(&mut self) {
+        let len = self.points.len();
+        if len < 2 {
+            return;
+        }
+        if self.looping {
+            self.target = (self.target + 1) % len;
+            return;
+        }
+        if (self.reversed && self.target == 0) || (!self.reversed && self.target == len - 1) {
+            self.reversed = !self.reversed;
+        }
+        if self.reversed {
+            self.target -= 1;
+        } else {
+            self.target += 1;
+        }
+    }
+}
+
+pub(super) fn follow_patrol(
+    time: Res<Time>,
+    mut patrol_query: Query<(&mut Patrol, &Transform, &mut MovementController)>,
+) {
+    let dt = time.delta_seconds();
+    for (mut patrol, transform, mut controller) in &mut patrol_query {
+        // Waypoints may have been removed at runtime.
+        if patrol.target >= patrol.points.len() {
+            patrol.target = 0;
+        }
+        let position = transform.translation.xy();
+        let Some(mut target) = patrol.target() else {
+            controller.intent = Vec2::ZERO;
+            continue;
+        };
+        // Don't get stuck on a waypoint if we're already there.
+        if position.distance(target) < f32::EPSILON {
+            patrol.advance();
+            target = patrol.target().unwrap_or(target);
+        }
+
+        controller.max_speed = patrol.speed;
+        let max_step = patrol.speed * dt;
+        let offset = target - position;
+        controller.intent = if max_step > 0.0 && offset.length() <= max_step {
+            // Slow down to land exactly on the waypoint this frame.
+            offset / max_step
+        } else {
+            offset.normalize_or_zero()
+        };
+    }
+}
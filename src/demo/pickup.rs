@@ -0,0 +1,233 @@
+//! Collectibles such as coins that the player picks up by touching them.
+//!
+//! Some kinds of pickups are used up right away instead of being collected, so
+//! they don't send [`PickupCollected`]: [`TIME_BONUS`] and [`TIME_PENALTY`]
+//! change the [`LevelTimer`] by their value in seconds, and [`HEAL`] heals the
+//! player by its value.
+
+use std::time::Duration;
+
+use bevy::{ecs::world::Command, prelude::*};
+
+use crate::{
+    asset_tracking::LoadResource,
+    audio::SoundEffect,
+    demo::{
+        collision::Collider,
+        gravity::{apply_gravity, AffectedByGravity, Landed},
+        health::HealEvent,
+        level_timer::LevelTimer,
+        player::Player,
+        sensor::{Sensor, TriggerEnter},
+    },
+    particles::{ParticleBurst, ParticleEmitter},
+    save::quicksave::Persistent,
+    screens::Screen,
+    spawn::SpawnWith as _,
+    AppSet,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<(Pickup, DropTo)>();
+    app.load_resource::<PickupAssets>();
+    app.add_event::<PickupCollected>();
+    app.add_systems(
+        FixedUpdate,
+        land_dropped_pickups
+            .after(apply_gravity)
+            .in_set(AppSet::Update),
+    );
+    app.observe(collect_pickup);
+}
+
+/// Something the player can collect.
+#[derive(Component, Reflect, Debug, Clone, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct Pickup {
+    /// What was collected, e.g. `"coin"`. Use any key your game needs.
+    pub kind: String,
+    /// How much it is worth.
+    pub value: u32,
+}
+
+/// The [`Pickup::kind`] that adds its value in seconds to the [`LevelTimer`].
+pub const TIME_BONUS: &str = "time_bonus";
+
+/// The [`Pickup::kind`] that subtracts its value in seconds from the [`LevelTimer`].
+pub const TIME_PENALTY: &str = "time_penalty";
+
+/// The [`Pickup::kind`] that heals the collector by its value.
+pub const HEAL: &str = "heal";
+
+/// The player collected a [`Pickup`].
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct PickupCollected {
+    /// The entity that collected the pickup.
+    pub collector: Entity,
+    pub kind: String,
+    pub value: u32,
+}
+
+/// A command to spawn a pickup.
+#[derive(Debug)]
+pub struct SpawnPickup {
+    pub pickup: Pickup,
+    /// Where to spawn the pickup in world units.
+    pub position: Vec2,
+    /// Spawn the pickup this far above `position` and let it fall down to it,
+    /// e.g. for loot dropped by enemies. Use `0.0` to place it directly.
+    pub drop_height: f32,
+}
+
+impl Command for SpawnPickup {
+    fn apply(self, world: &mut World) {
+        world.spawn_with_data(self, spawn_pickup);
+    }
+}
+
+/// The height that a dropped pickup falls down to.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+struct DropTo(f32);
+
+const PICKUP_SIZE: f32 = 24.0;
+const PICKUP_COLOR: Color = Color::srgb(1.0, 0.8, 0.1);
+const TIME_BONUS_COLOR: Color = Color::srgb(0.3, 0.8, 1.0);
+const TIME_PENALTY_COLOR: Color = Color::srgb(0.8, 0.2, 0.9);
+const HEAL_COLOR: Color = Color::srgb(0.3, 0.9, 0.4);
+const DROP_GRAVITY: f32 = 2000.0;
+const DROP_TERMINAL_SPEED: f32 = 800.0;
+
+fn spawn_pickup(In(config): In<SpawnPickup>, mut commands: Commands) {
+    let spawn_position = config.position + Vec2::Y * config.drop_height;
+    let color = match config.pickup.kind.as_str() {
+        TIME_BONUS => TIME_BONUS_COLOR,
+        TIME_PENALTY => TIME_PENALTY_COLOR,
+        HEAL => HEAL_COLOR,
+        _ => PICKUP_COLOR,
+    };
+    let mut pickup = commands.spawn((
+        Name::new("Pickup"),
+        config.pickup,
+        SpriteBundle {
+            sprite: Sprite {
+                color,
+                custom_size: Some(Vec2::splat(PICKUP_SIZE)),
+                ..default()
+            },
+            transform: Transform::from_translation(spawn_position.extend(0.0)),
+            ..default()
+        },
+        Collider::new(Vec2::splat(PICKUP_SIZE / 2.0)),
+        Sensor,
+        Persistent,
+        StateScoped(Screen::Gameplay),
+    ));
+    if config.drop_height > 0.0 {
+        pickup.insert((
+            AffectedByGravity::new(DROP_GRAVITY, DROP_TERMINAL_SPEED),
+            DropTo(config.position.y),
+        ));
+    }
+}
+
+/// Stop dropped pickups once they reach the height they were dropped to.
+fn land_dropped_pickups(
+    mut commands: Commands,
+    mut pickup_query: Query<(Entity, &DropTo, &mut Transform), With<AffectedByGravity>>,
+) {
+    for (entity, drop_to, mut transform) in &mut pickup_query {
+        if transform.translation.y > drop_to.0 {
+            continue;
+        }
+        transform.translation.y = drop_to.0;
+        commands.trigger_targets(Landed, entity);
+        commands
+            .entity(entity)
+            .remove::<(AffectedByGravity, DropTo)>();
+    }
+}
+
+const SPARKLE_COLOR: Color = Color::srgb(1.0, 0.85, 0.3);
+
+fn collect_pickup(
+    trigger: Trigger<TriggerEnter>,
+    mut commands: Commands,
+    pickup_query: Query<(&Pickup, &Transform)>,
+    player_query: Query<(), With<Player>>,
+    pickup_assets: Res<PickupAssets>,
+    level_timer: Option<ResMut<LevelTimer>>,
+    mut heal_events: EventWriter<HealEvent>,
+    mut collected_events: EventWriter<PickupCollected>,
+) {
+    // The event is triggered on both the sensor and the other entity,
+    // so only handle it once.
+    let event = trigger.event();
+    if trigger.entity() != event.sensor || !player_query.contains(event.other) {
+        return;
+    }
+    let Ok((pickup, transform)) = pickup_query.get(event.sensor) else {
+        return;
+    };
+
+    let time = Duration::from_secs(pickup.value.into());
+    match pickup.kind.as_str() {
+        TIME_BONUS => {
+            if let Some(mut level_timer) = level_timer {
+                level_timer.add(time);
+            }
+        }
+        TIME_PENALTY => {
+            if let Some(mut level_timer) = level_timer {
+                level_timer.subtract(time);
+            }
+        }
+        HEAL => {
+            heal_events.send(HealEvent {
+                target: event.other,
+                amount: pickup.value as f32,
+            });
+        }
+        _ => {
+            collected_events.send(PickupCollected {
+                collector: event.other,
+                kind: pickup.kind.clone(),
+                value: pickup.value,
+            });
+        }
+    }
+    commands.spawn((
+        AudioBundle {
+            source: pickup_assets.collect.clone(),
+            settings: PlaybackSettings::DESPAWN,
+        },
+        SoundEffect,
+    ));
+    commands.trigger(ParticleBurst {
+        position: transform.translation.xy(),
+        count: 16,
+        emitter: ParticleEmitter::new(0.0, 0.4, Vec2::splat(-150.0)..Vec2::splat(150.0))
+            .with_color_over_life(SPARKLE_COLOR, SPARKLE_COLOR.with_alpha(0.0)),
+    });
+    commands.entity(event.sensor).despawn_recursive();
+}
+
+#[derive(Resource, Asset, Reflect, Clone)]
+pub struct PickupAssets {
+    #[dependency]
+    pub collect: Handle<AudioSource>,
+}
+
+impl PickupAssets {
+    // Replace this with a dedicated pickup sound.
+    pub const PATH_COLLECT: &'static str = "audio/sound_effects/button_hover.ogg";
+}
+
+impl FromWorld for PickupAssets {
+    fn from_world(world: &mut World) -> Self {
+        let assets = world.resource::<AssetServer>();
+        Self {
+            collect: assets.load(PickupAssets::PATH_COLLECT),
+        }
+    }
+}
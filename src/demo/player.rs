@@ -2,19 +2,34 @@
 //! Note that this is separate from the `movement` module as that could be used
 //! for other characters as well.
 
+use std::time::Duration;
+
 use bevy::{
-    ecs::{system::RunSystemOnce as _, world::Command},
+    ecs::world::Command,
     prelude::*,
     render::texture::{ImageLoaderSettings, ImageSampler},
 };
 
 use crate::{
     asset_tracking::LoadResource,
+    config::GameConfig,
     demo::{
-        animation::PlayerAnimation,
-        movement::{MovementController, ScreenWrap},
+        animation::{AnimationClips, AnimationController},
+        collision::Collider,
+        damage_flash::{DamageFlash, WHITE_FLASH},
+        grid_movement::GridMovement,
+        health::{Health, Invulnerability},
+        inventory::Inventory,
+        key_bindings::{action_just_pressed, Action, KeyBindings},
+        movement::{Facing, MovementController, PhysicalTransform, ScreenWrap},
+        projectile::SpawnProjectile,
+        sprite_variant::SpriteVariant,
+        squash_stretch::SquashStretch,
+        trail::Trail,
     },
+    save::quicksave::Persistent,
     screens::Screen,
+    spawn::SpawnWith as _,
     AppSet,
 };
 
@@ -27,29 +42,59 @@ pub(super) fn plugin(app: &mut App) {
         Update,
         record_player_directional_input.in_set(AppSet::RecordInput),
     );
+
+    // Shoot projectiles.
+    app.add_systems(
+        Update,
+        fire_projectile
+            .run_if(action_just_pressed(Action::Fire))
+            .in_set(AppSet::RecordInput),
+    );
+
+    // Apply config changes to the player while the game is running.
+    app.add_systems(
+        Update,
+        apply_player_config.run_if(resource_changed::<GameConfig>),
+    );
 }
 
 #[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
 #[reflect(Component)]
 pub struct Player;
 
+/// Draw the player in front of the level.
+const PLAYER_Z: f32 = 1.0;
+
+/// Scale up the pixel art so that it's visible.
+const PLAYER_SCALE: Vec2 = Vec2::splat(8.0);
+
+/// How quickly the player returns to [`PLAYER_SCALE`] after deforming.
+const SQUASH_STRETCH_RECOVERY: f32 = 12.0;
+
+/// The tile size for [`GridMovement`], which matches the tiles of the level.
+const GRID_TILE_SIZE: f32 = 64.0;
+
 /// A command to spawn the player character.
 #[derive(Debug)]
 pub struct SpawnPlayer {
     /// See [`MovementController::max_speed`].
     pub max_speed: f32,
+    /// Where to spawn the player in world units.
+    pub position: Vec2,
 }
 
 impl Command for SpawnPlayer {
     fn apply(self, world: &mut World) {
-        world.run_system_once_with(self, spawn_player);
+        world.spawn_with_data(self, spawn_player);
     }
 }
 
 fn spawn_player(
     In(config): In<SpawnPlayer>,
     mut commands: Commands,
+    game_config: Res<GameConfig>,
     player_assets: Res<PlayerAssets>,
+    player_query: Query<(), With<Player>>,
     mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
 ) {
     // A texture atlas is a way to split one image with a grid into multiple
@@ -59,46 +104,76 @@ fn spawn_player(
     // this example: https://github.com/bevyengine/bevy/blob/latest/examples/2d/texture_atlas.rs
     let layout = TextureAtlasLayout::from_grid(UVec2::splat(32), 6, 2, Some(UVec2::splat(1)), None);
     let texture_atlas_layout = texture_atlas_layouts.add(layout);
-    let player_animation = PlayerAnimation::new();
+    let animation = AnimationController::new(player_assets.animations.clone(), "idle");
 
-    commands.spawn((
+    let mut player = commands.spawn((
         Name::new("Player"),
         Player,
         SpriteBundle {
             texture: player_assets.ducky.clone(),
-            transform: Transform::from_scale(Vec2::splat(8.0).extend(1.0)),
+            transform: Transform::from_translation(config.position.extend(PLAYER_Z))
+                .with_scale(PLAYER_SCALE.extend(1.0)),
             ..Default::default()
         },
         TextureAtlas {
             layout: texture_atlas_layout.clone(),
-            index: player_animation.get_atlas_index(),
+            index: 0,
         },
         MovementController {
             max_speed: config.max_speed,
             ..default()
         },
+        Facing::default(),
         ScreenWrap,
-        player_animation,
+        // The ducky only covers part of its sprite.
+        Collider::new(Vec2::new(48.0, 64.0)),
+        Health::new(100.0),
+        DamageFlash::new(WHITE_FLASH, 0.1),
+        Invulnerability::new(1.0),
+        Inventory::default(),
+        animation,
+        Persistent,
         StateScoped(Screen::Gameplay),
     ));
+    player.insert((
+        // Give each additional player a different color.
+        SpriteVariant::for_player(player_query.iter().count()),
+        Trail::new(Duration::from_millis(40), Duration::from_millis(250)),
+    ));
+    if game_config.player.squash_stretch > 0.0 {
+        player.insert(SquashStretch::new(
+            game_config.player.squash_stretch,
+            SQUASH_STRETCH_RECOVERY,
+            PLAYER_SCALE,
+        ));
+    }
+    if game_config.player.grid_movement {
+        // The player spawns at the center of a tile.
+        player.insert(GridMovement::new(GRID_TILE_SIZE, config.position));
+    } else if game_config.player.fixed_timestep {
+        player.insert(PhysicalTransform::from_translation(
+            config.position.extend(PLAYER_Z),
+        ));
+    }
 }
 
 fn record_player_directional_input(
     input: Res<ButtonInput<KeyCode>>,
+    bindings: Res<KeyBindings>,
     mut controller_query: Query<&mut MovementController, With<Player>>,
 ) {
     // Collect directional input.
     let mut intent = Vec2::ZERO;
-    if input.pressed(KeyCode::KeyW) || input.pressed(KeyCode::ArrowUp) {
+    if bindings.pressed(Action::MoveUp, &input) {
         intent.y += 1.0;
     }
-    if input.pressed(KeyCode::KeyS) || input.pressed(KeyCode::ArrowDown) {
+    if bindings.pressed(Action::MoveDown, &input) {
         intent.y -= 1.0;
     }
-    if input.pressed(KeyCode::KeyA) || input.pressed(KeyCode::ArrowLeft) {
+    if bindings.pressed(Action::MoveLeft, &input) {
         intent.x -= 1.0;
     }
-    if input.pressed(KeyCode::KeyD) || input.pressed(KeyCode::ArrowRight) {
+    if bindings.pressed(Action::MoveRight, &input) {
         intent.x += 1.0;
     }
 
@@ -113,6 +188,38 @@ fn record_player_directional_input(
     }
 }
 
+fn apply_player_config(
+    config: Res<GameConfig>,
+    mut controller_query: Query<&mut MovementController, With<Player>>,
+    mut squash_stretch_query: Query<&mut SquashStretch, With<Player>>,
+) {
+    for mut controller in &mut controller_query {
+        controller.max_speed = config.player.max_speed;
+    }
+    for mut squash_stretch in &mut squash_stretch_query {
+        squash_stretch.intensity = config.player.squash_stretch;
+    }
+}
+
+/// Fire a projectile in the direction the player is moving or facing.
+fn fire_projectile(
+    mut commands: Commands,
+    player_query: Query<(Entity, &Transform, &MovementController, &Facing), With<Player>>,
+) {
+    for (entity, transform, controller, facing) in &player_query {
+        let direction = if controller.intent != Vec2::ZERO {
+            controller.intent
+        } else {
+            facing.direction()
+        };
+        commands.add(SpawnProjectile {
+            position: transform.translation.xy(),
+            direction,
+            owner: entity,
+        });
+    }
+}
+
 #[derive(Resource, Asset, Reflect, Clone)]
 pub struct PlayerAssets {
     // This #[dependency] attribute marks the field as a dependency of the Asset.
@@ -121,6 +228,8 @@ pub struct PlayerAssets {
     pub ducky: Handle<Image>,
     #[dependency]
     pub steps: Vec<Handle<AudioSource>>,
+    #[dependency]
+    pub animations: Handle<AnimationClips>,
 }
 
 impl PlayerAssets {
@@ -129,6 +238,7 @@ impl PlayerAssets {
     pub const PATH_STEP_2: &'static str = "audio/sound_effects/step2.ogg";
     pub const PATH_STEP_3: &'static str = "audio/sound_effects/step3.ogg";
     pub const PATH_STEP_4: &'static str = "audio/sound_effects/step4.ogg";
+    pub const PATH_ANIMATIONS: &'static str = "animations/ducky.ron";
 }
 
 impl FromWorld for PlayerAssets {
@@ -148,6 +258,7 @@ impl FromWorld for PlayerAssets {
                 assets.load(PlayerAssets::PATH_STEP_3),
                 assets.load(PlayerAssets::PATH_STEP_4),
             ],
+            animations: assets.load(PlayerAssets::PATH_ANIMATIONS),
         }
     }
 }
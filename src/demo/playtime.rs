@@ -0,0 +1,90 @@
+//! Lifetime stats across all runs, such as the total time played.
+//!
+//! [`PlaytimeStats`] is a [`Setting`], but it changes every frame, so it's only
+//! stored periodically and on exit instead of on every change.
+
+use std::time::Duration;
+
+use bevy::{prelude::*, window::PrimaryWindow};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    settings::{RegisterSetting as _, Setting, SettingsStore},
+    sim_control::SimControl,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_setting::<PlaytimeStats>();
+    app.init_resource::<PlaytimeStoreTimer>();
+    app.add_systems(Startup, start_session);
+    app.add_systems(Update, (tick_playtime, store_playtime_periodically).chain());
+    // Runs before the settings are flushed on exit in `Last`.
+    app.add_systems(
+        PostUpdate,
+        store_playtime_on_exit.run_if(on_event::<AppExit>()),
+    );
+}
+
+/// How often the playtime is stored, so that a crash doesn't lose much of it.
+const STORE_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Resource, Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+#[serde(default)]
+pub struct PlaytimeStats {
+    /// Time spent with the game focused and not paused, across all launches.
+    pub total: Duration,
+    /// The number of times the game was launched.
+    pub sessions: u32,
+}
+
+impl Setting for PlaytimeStats {
+    const KEY: &'static str = "playtime";
+}
+
+impl PlaytimeStats {
+    /// Forget all stats. They are stored again shortly after.
+    pub fn reset(&mut self) {
+        *self = default();
+    }
+}
+
+#[derive(Resource, Deref, DerefMut)]
+struct PlaytimeStoreTimer(Timer);
+
+impl Default for PlaytimeStoreTimer {
+    fn default() -> Self {
+        Self(Timer::new(STORE_INTERVAL, TimerMode::Repeating))
+    }
+}
+
+fn start_session(mut stats: ResMut<PlaytimeStats>) {
+    stats.sessions += 1;
+}
+
+fn tick_playtime(
+    time: Res<Time<Real>>,
+    sim_control: Res<SimControl>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut stats: ResMut<PlaytimeStats>,
+) {
+    let focused = window_query.get_single().is_ok_and(|window| window.focused);
+    if !focused || sim_control.is_paused() {
+        return;
+    }
+    // Bypass change detection so that the stats aren't stored on every frame.
+    stats.bypass_change_detection().total += time.delta();
+}
+
+fn store_playtime_periodically(
+    time: Res<Time<Real>>,
+    mut timer: ResMut<PlaytimeStoreTimer>,
+    mut stats: ResMut<PlaytimeStats>,
+) {
+    if timer.tick(time.delta()).just_finished() {
+        stats.set_changed();
+    }
+}
+
+fn store_playtime_on_exit(stats: Res<PlaytimeStats>, mut store: ResMut<SettingsStore>) {
+    store.save(PlaytimeStats::KEY, stats.as_ref());
+}
@@ -0,0 +1,188 @@
+//! Projectiles that fly in a straight line and damage what they hit.
+//!
+//! Projectiles are fired often, so they are recycled with a [`Pool`] instead
+//! of being despawned.
+
+use bevy::{ecs::world::Command, prelude::*, utils::HashSet};
+
+use crate::{
+    demo::{
+        collision::{detect_collisions, Collider, CollisionEvent},
+        health::{DamageEvent, Health},
+        level::WorldBounds,
+        sensor::Sensor,
+        spatial_grid::update_spatial_grid,
+    },
+    particles::{ParticleBurst, ParticleEmitter},
+    pool::{Pool, Pooled},
+    screens::Screen,
+    spawn::SpawnWith as _,
+    AppSet,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<(Projectile, Pooled)>();
+    app.init_resource::<Pool<Projectile>>();
+    app.add_systems(
+        Update,
+        (
+            tick_projectile_lifetime.in_set(AppSet::TickTimers),
+            (
+                move_projectiles.before(update_spatial_grid),
+                hit_with_projectiles.after(detect_collisions),
+                // Projectiles that hit something are already released.
+                release_expired_projectiles.after(hit_with_projectiles),
+            )
+                .in_set(AppSet::Update),
+        ),
+    );
+}
+
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component)]
+pub struct Projectile {
+    /// Velocity in world units per second.
+    pub velocity: Vec2,
+    /// The projectile is released back into the pool when this finishes.
+    pub lifetime: Timer,
+    /// Damage dealt to the [`Health`] of whatever it hits.
+    pub damage: f32,
+    /// The entity that fired the projectile, which it can't hit.
+    pub owner: Entity,
+}
+
+/// A command to spawn a projectile.
+#[derive(Debug)]
+pub struct SpawnProjectile {
+    /// Where to spawn the projectile in world units.
+    pub position: Vec2,
+    /// The direction to fire in. This does not need to be normalized.
+    pub direction: Vec2,
+    /// See [`Projectile::owner`].
+    pub owner: Entity,
+}
+
+impl Command for SpawnProjectile {
+    fn apply(self, world: &mut World) {
+        world.spawn_with_data(self, spawn_projectile);
+    }
+}
+
+const PROJECTILE_SPEED: f32 = 900.0;
+const PROJECTILE_LIFETIME_SECS: f32 = 2.0;
+const PROJECTILE_DAMAGE: f32 = 10.0;
+const PROJECTILE_SIZE: f32 = 16.0;
+const PROJECTILE_COLOR: Color = Color::srgb(1.0, 0.9, 0.3);
+
+fn spawn_projectile(
+    In(config): In<SpawnProjectile>,
+    mut commands: Commands,
+    mut pool: ResMut<Pool<Projectile>>,
+) {
+    let direction = config.direction.normalize_or_zero();
+    if direction == Vec2::ZERO {
+        return;
+    }
+
+    let projectile = (
+        Name::new("Projectile"),
+        Projectile {
+            velocity: direction * PROJECTILE_SPEED,
+            lifetime: Timer::from_seconds(PROJECTILE_LIFETIME_SECS, TimerMode::Once),
+            damage: PROJECTILE_DAMAGE,
+            owner: config.owner,
+        },
+        SpriteBundle {
+            sprite: Sprite {
+                color: PROJECTILE_COLOR,
+                custom_size: Some(Vec2::splat(PROJECTILE_SIZE)),
+                ..default()
+            },
+            transform: Transform::from_translation(config.position.extend(2.0)),
+            ..default()
+        },
+        Collider::new(Vec2::splat(PROJECTILE_SIZE / 2.0)),
+        StateScoped(Screen::Gameplay),
+    );
+    match pool.acquire(&mut commands) {
+        Some(mut entity) => {
+            entity.insert(projectile);
+        }
+        None => {
+            commands.spawn(projectile);
+        }
+    }
+}
+
+/// Put a projectile back into the [`Pool`].
+fn release_projectile(commands: &mut Commands, pool: &mut Pool<Projectile>, entity: Entity) {
+    let mut entity = commands.entity(entity);
+    // Stop moving and colliding.
+    entity.remove::<(Projectile, Collider)>();
+    pool.release(entity);
+}
+
+fn tick_projectile_lifetime(time: Res<Time>, mut projectile_query: Query<&mut Projectile>) {
+    for mut projectile in &mut projectile_query {
+        projectile.lifetime.tick(time.delta());
+    }
+}
+
+fn move_projectiles(time: Res<Time>, mut projectile_query: Query<(&Projectile, &mut Transform)>) {
+    for (projectile, mut transform) in &mut projectile_query {
+        transform.translation += projectile.velocity.extend(0.0) * time.delta_seconds();
+    }
+}
+
+/// Release projectiles that are too old or have left the level.
+fn release_expired_projectiles(
+    mut commands: Commands,
+    mut pool: ResMut<Pool<Projectile>>,
+    bounds: Option<Res<WorldBounds>>,
+    projectile_query: Query<(Entity, &Projectile, &Transform)>,
+) {
+    for (entity, projectile, transform) in &projectile_query {
+        let out_of_bounds = bounds
+            .as_ref()
+            .is_some_and(|bounds| !bounds.0.contains(transform.translation.xy()));
+        if projectile.lifetime.finished() || out_of_bounds {
+            commands.trigger(ParticleBurst {
+                position: transform.translation.xy(),
+                count: 8,
+                emitter: ParticleEmitter::new(0.0, 0.2, Vec2::splat(-100.0)..Vec2::splat(100.0))
+                    .with_size_over_life(6.0, 1.0),
+            });
+            release_projectile(&mut commands, &mut pool, entity);
+        }
+    }
+}
+
+/// Damage and release on the first thing a projectile hits.
+fn hit_with_projectiles(
+    mut commands: Commands,
+    mut pool: ResMut<Pool<Projectile>>,
+    mut collision_events: EventReader<CollisionEvent>,
+    projectile_query: Query<(&Projectile, &Transform)>,
+    ignore_query: Query<(), Or<(With<Projectile>, With<Sensor>)>>,
+    health_query: Query<(), With<Health>>,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    let mut hit = HashSet::new();
+    for event in collision_events.read() {
+        for (entity, other) in [(event.a, event.b), (event.b, event.a)] {
+            let Ok((projectile, transform)) = projectile_query.get(entity) else {
+                continue;
+            };
+            if other == projectile.owner || ignore_query.contains(other) || !hit.insert(entity) {
+                continue;
+            }
+            if health_query.contains(other) {
+                damage_events.send(DamageEvent {
+                    target: other,
+                    amount: projectile.damage,
+                });
+            }
+            release_projectile(&mut commands, &mut pool, entity);
+        }
+    }
+}
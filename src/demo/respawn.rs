@@ -0,0 +1,121 @@
+//! Bring the player back at the last checkpoint when they die.
+//!
+//! Touching a [`Checkpoint`] moves the [`RespawnPoint`] there. When the
+//! player's [`DeathEvent`] is triggered, they are moved back to it with full
+//! health and brief invulnerability.
+
+use bevy::{ecs::world::Command, prelude::*};
+
+use crate::{
+    demo::{
+        collision::Collider,
+        health::{DeathEvent, Health, Invulnerability},
+        movement::PhysicalTransform,
+        player::Player,
+        sensor::{Sensor, TriggerEnter},
+    },
+    screens::Screen,
+    spawn::SpawnWith as _,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<(Checkpoint, RespawnPoint)>();
+    app.init_resource::<RespawnPoint>();
+    app.observe(reach_checkpoint);
+    app.observe(respawn_player);
+}
+
+/// Where the player respawns after dying.
+/// This is the level start until a [`Checkpoint`] is reached.
+#[derive(Resource, Reflect, Debug, Default, Clone, Copy, PartialEq)]
+#[reflect(Resource)]
+pub struct RespawnPoint(pub Vec2);
+
+/// A sensor that moves the [`RespawnPoint`] to itself when the player touches it.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Component)]
+pub struct Checkpoint;
+
+/// A command to spawn a checkpoint.
+#[derive(Debug)]
+pub struct SpawnCheckpoint {
+    /// Where to spawn the checkpoint in world units.
+    pub position: Vec2,
+    /// The width and height of the checkpoint in world units.
+    pub size: f32,
+}
+
+impl Command for SpawnCheckpoint {
+    fn apply(self, world: &mut World) {
+        world.spawn_with_data(self, spawn_checkpoint);
+    }
+}
+
+const CHECKPOINT_COLOR: Color = Color::srgb(0.2, 0.4, 0.6);
+
+fn spawn_checkpoint(In(config): In<SpawnCheckpoint>, mut commands: Commands) {
+    commands.spawn((
+        Name::new("Checkpoint"),
+        Checkpoint,
+        SpriteBundle {
+            sprite: Sprite {
+                color: CHECKPOINT_COLOR,
+                custom_size: Some(Vec2::splat(config.size)),
+                ..default()
+            },
+            transform: Transform::from_translation(config.position.extend(-1.5)),
+            ..default()
+        },
+        Collider::new(Vec2::splat(config.size / 2.0)),
+        Sensor,
+        StateScoped(Screen::Gameplay),
+    ));
+}
+
+fn reach_checkpoint(
+    trigger: Trigger<TriggerEnter>,
+    checkpoint_query: Query<&Transform, With<Checkpoint>>,
+    player_query: Query<(), With<Player>>,
+    mut respawn_point: ResMut<RespawnPoint>,
+) {
+    // The event is triggered on both the sensor and the other entity,
+    // so only handle it once.
+    let event = trigger.event();
+    if trigger.entity() != event.sensor || !player_query.contains(event.other) {
+        return;
+    }
+    let Ok(transform) = checkpoint_query.get(event.sensor) else {
+        return;
+    };
+    respawn_point.set_if_neq(RespawnPoint(transform.translation.xy()));
+}
+
+fn respawn_player(
+    trigger: Trigger<DeathEvent>,
+    respawn_point: Res<RespawnPoint>,
+    mut player_query: Query<
+        (
+            &mut Transform,
+            &mut Health,
+            Option<&mut Invulnerability>,
+            Option<&mut PhysicalTransform>,
+        ),
+        With<Player>,
+    >,
+) {
+    let Ok((mut transform, mut health, invulnerability, physical)) =
+        player_query.get_mut(trigger.entity())
+    else {
+        return;
+    };
+
+    let translation = respawn_point.0.extend(transform.translation.z);
+    transform.translation = translation;
+    if let Some(mut physical) = physical {
+        *physical = PhysicalTransform::from_translation(translation);
+    }
+    health.current = health.max;
+    if let Some(mut invulnerability) = invulnerability {
+        invulnerability.start();
+    }
+}
@@ -0,0 +1,54 @@
+//! Bookkeeping for the current run, such as the score and time played.
+//!
+//! [`RunStats`] is reset whenever a level is spawned, so screens shown
+//! at the end of a run can read the results from it.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{demo::pickup::PickupCollected, screens::Screen, AppSet};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<RunStats>();
+    app.init_resource::<RunStats>();
+    app.add_systems(
+        Update,
+        (
+            tick_elapsed
+                .in_set(AppSet::TickTimers)
+                .run_if(in_state(Screen::Gameplay)),
+            count_pickups.in_set(AppSet::Update),
+        ),
+    );
+}
+
+#[derive(Resource, Reflect, Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+#[reflect(Resource)]
+pub struct RunStats {
+    /// Time spent in gameplay during this run.
+    pub elapsed: Duration,
+    /// The total value of everything collected.
+    pub score: u32,
+    /// The number of pickups collected.
+    pub pickups: u32,
+}
+
+impl RunStats {
+    /// Start a new run.
+    pub fn reset(&mut self) {
+        *self = default();
+    }
+}
+
+fn tick_elapsed(time: Res<Time>, mut stats: ResMut<RunStats>) {
+    stats.elapsed += time.delta();
+}
+
+fn count_pickups(mut collected_events: EventReader<PickupCollected>, mut stats: ResMut<RunStats>) {
+    for event in collected_events.read() {
+        stats.score += event.value;
+        stats.pickups += 1;
+    }
+}
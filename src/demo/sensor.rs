@@ -0,0 +1,89 @@
+//! Non-solid trigger zones, e.g. for checkpoints and hazards.
+//!
+//! A [`Sensor`] produces a [`TriggerEnter`] when another [`Collider`] starts
+//! overlapping it and a [`TriggerExit`] when it stops overlapping it. Like
+//! [`CollisionEvent`](crate::demo::collision::CollisionEvent), these are sent as
+//! regular events and triggered on both entities.
+
+use bevy::{prelude::*, utils::HashSet};
+
+use crate::{
+    demo::{
+        collision::{overlaps, Collider},
+        spatial_grid::{update_spatial_grid, SpatialGrid},
+    },
+    AppSet,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Sensor>();
+    app.init_resource::<SensorOverlaps>();
+    app.add_event::<TriggerEnter>();
+    app.add_event::<TriggerExit>();
+    app.add_systems(
+        Update,
+        detect_sensor_overlaps
+            .after(update_spatial_grid)
+            .in_set(AppSet::Update),
+    );
+}
+
+/// Marks a [`Collider`] as a trigger zone. Sensors don't trigger each other.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Component)]
+pub struct Sensor;
+
+/// A collider started overlapping a sensor this frame.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerEnter {
+    pub sensor: Entity,
+    pub other: Entity,
+}
+
+/// A collider stopped overlapping a sensor this frame.
+/// This also happens when either of them is despawned.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerExit {
+    pub sensor: Entity,
+    pub other: Entity,
+}
+
+/// The `(sensor, other)` pairs that overlapped last frame.
+#[derive(Resource, Debug, Default)]
+struct SensorOverlaps(HashSet<(Entity, Entity)>);
+
+fn detect_sensor_overlaps(
+    mut commands: Commands,
+    grid: Res<SpatialGrid>,
+    sensor_query: Query<(Entity, &Collider, &Transform), With<Sensor>>,
+    collider_query: Query<(Entity, &Collider, &Transform), Without<Sensor>>,
+    mut overlaps_last_frame: ResMut<SensorOverlaps>,
+    mut enter_events: EventWriter<TriggerEnter>,
+    mut exit_events: EventWriter<TriggerExit>,
+) {
+    let mut overlaps_this_frame = HashSet::new();
+    for (sensor, sensor_collider, sensor_transform) in &sensor_query {
+        let sensor_aabb = sensor_collider.aabb(sensor_transform.translation.xy());
+        for other in grid.query_region(sensor_aabb) {
+            let Ok((_, collider, transform)) = collider_query.get(other) else {
+                continue;
+            };
+            if overlaps(sensor_aabb, collider.aabb(transform.translation.xy())) {
+                overlaps_this_frame.insert((sensor, other));
+            }
+        }
+    }
+
+    for &(sensor, other) in overlaps_this_frame.difference(&overlaps_last_frame.0) {
+        let event = TriggerEnter { sensor, other };
+        enter_events.send(event);
+        commands.trigger_targets(event, [sensor, other]);
+    }
+    for &(sensor, other) in overlaps_last_frame.0.difference(&overlaps_this_frame) {
+        let event = TriggerExit { sensor, other };
+        exit_events.send(event);
+        commands.trigger_targets(event, [sensor, other]);
+    }
+
+    overlaps_last_frame.0 = overlaps_this_frame;
+}
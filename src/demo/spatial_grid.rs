@@ -0,0 +1,219 @@
+//! Bucket colliders into grid cells to quickly find the ones near a region.
+//!
+//! The grid is rebuilt every frame after movement. Systems that look for
+//! nearby colliders should run after [`update_spatial_grid`].
+
+use bevy::{
+    prelude::*,
+    utils::{HashMap, HashSet},
+};
+
+use crate::{
+    config::{GameConfig, SpatialGridConfig},
+    demo::{collision::Collider, movement::apply_screen_wrap},
+    AppSet,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<SpatialGrid>();
+    app.add_systems(
+        Update,
+        (
+            apply_spatial_grid_config.run_if(resource_changed::<GameConfig>),
+            update_spatial_grid,
+        )
+            .chain()
+            .after(apply_screen_wrap)
+            .in_set(AppSet::Update),
+    );
+}
+
+/// Colliders bucketed by the grid cells their bounding boxes overlap.
+#[derive(Resource, Debug)]
+pub struct SpatialGrid {
+    /// The width and height of a cell in world units.
+    /// Cells about the size of a typical collider work best.
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<Entity>>,
+}
+
+impl Default for SpatialGrid {
+    fn default() -> Self {
+        Self::new(SpatialGridConfig::default().cell_size)
+    }
+}
+
+impl SpatialGrid {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cells: HashMap::new(),
+        }
+    }
+
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size
+    }
+
+    /// Change the cell size. This clears the grid until it is rebuilt.
+    pub fn set_cell_size(&mut self, cell_size: f32) {
+        self.cell_size = cell_size;
+        self.clear();
+    }
+
+    pub fn clear(&mut self) {
+        self.cells.clear();
+    }
+
+    /// Add an entity to every cell its bounding box overlaps.
+    pub fn insert(&mut self, entity: Entity, aabb: Rect) {
+        let (min, max) = self.cell_range(aabb);
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                self.cells.entry(IVec2::new(x, y)).or_default().push(entity);
+            }
+        }
+    }
+
+    /// All entities in the cells overlapping `region`, each returned once.
+    /// These are only candidates: their bounding boxes may not overlap `region` itself.
+    pub fn query_region(&self, region: Rect) -> impl Iterator<Item = Entity> {
+        let (min, max) = self.cell_range(region);
+        let mut entities = HashSet::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                if let Some(cell) = self.cells.get(&IVec2::new(x, y)) {
+                    entities.extend(cell.iter().copied());
+                }
+            }
+        }
+        entities.into_iter()
+    }
+
+    /// All entities in the cells crossed by the line segment from `start` to `end`,
+    /// each returned once. Like with [`Self::query_region`], these are only candidates.
+    pub fn query_segment(&self, start: Vec2, end: Vec2) -> impl Iterator<Item = Entity> {
+        let mut entities = HashSet::new();
+        for cell in self.cells_along(start, end) {
+            if let Some(cell) = self.cells.get(&cell) {
+                entities.extend(cell.iter().copied());
+            }
+        }
+        entities.into_iter()
+    }
+
+    /// The cells crossed by the line segment from `start` to `end`, in order.
+    /// This walks the grid one cell at a time with a DDA, so no cell is skipped.
+    fn cells_along(&self, start: Vec2, end: Vec2) -> Vec<IVec2> {
+        let start_cell = (start / self.cell_size).floor().as_ivec2();
+        let end_cell = (end / self.cell_size).floor().as_ivec2();
+        let delta = end - start;
+
+        let mut step = IVec2::ZERO;
+        // How far along the segment the next cell boundary is crossed on each axis,
+        // and how far it is between two boundaries, both as a fraction of the segment.
+        let mut t_next = Vec2::INFINITY;
+        let mut t_delta = Vec2::INFINITY;
+        for axis in 0..2 {
+            if delta[axis] == 0.0 {
+                continue;
+            }
+            step[axis] = if delta[axis] > 0.0 { 1 } else { -1 };
+            let boundary = (start_cell[axis] + step[axis].max(0)) as f32 * self.cell_size;
+            t_next[axis] = (boundary - start[axis]) / delta[axis];
+            t_delta[axis] = self.cell_size / delta[axis].abs();
+        }
+
+        let mut cell = start_cell;
+        let mut cells = vec![cell];
+        // Every step moves one cell closer to the end on one axis.
+        let steps = (end_cell - start_cell).abs();
+        for _ in 0..steps.x + steps.y {
+            let axis = if t_next.x < t_next.y { 0 } else { 1 };
+            cell[axis] += step[axis];
+            t_next[axis] += t_delta[axis];
+            cells.push(cell);
+        }
+        cells
+    }
+
+    /// The first and last cells overlapped by `aabb`.
+    fn cell_range(&self, aabb: Rect) -> (IVec2, IVec2) {
+        let min = (aabb.min / self.cell_size).floor().as_ivec2();
+        let max = (aabb.max / self.cell_size).floor().as_ivec2();
+        (min, max)
+    }
+}
+
+/// The smallest allowed cell size, so that invalid configs can't break the grid.
+const MIN_CELL_SIZE: f32 = 1.0;
+
+fn apply_spatial_grid_config(config: Res<GameConfig>, mut grid: ResMut<SpatialGrid>) {
+    // This also replaces NaN.
+    let cell_size = config.spatial_grid.cell_size.max(MIN_CELL_SIZE);
+    if grid.cell_size() != cell_size {
+        grid.set_cell_size(cell_size);
+    }
+}
+
+pub(super) fn update_spatial_grid(
+    mut grid: ResMut<SpatialGrid>,
+    collider_query: Query<(Entity, &Collider, &Transform)>,
+) {
+    grid.clear();
+    for (entity, collider, transform) in &collider_query {
+        grid.insert(entity, collider.aabb(transform.translation.xy()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::utils::HashSet;
+
+    use super::*;
+    use crate::rng::GameRng;
+
+    fn overlaps(a: Rect, b: Rect) -> bool {
+        !a.intersect(b).is_empty()
+    }
+
+    /// Checking only the colliders that share a cell finds the same
+    /// overlapping pairs as checking every pair against each other.
+    #[test]
+    fn broad_phase_matches_naive_pairs() {
+        let mut rng = GameRng::from_seed(42);
+        for _ in 0..20 {
+            let boxes: Vec<(Entity, Rect)> = (0..50)
+                .map(|i| {
+                    let center = Vec2::new(rng.range(-500.0..500.0), rng.range(-500.0..500.0));
+                    let size = Vec2::new(rng.range(1.0..200.0), rng.range(1.0..200.0));
+                    (Entity::from_raw(i), Rect::from_center_size(center, size))
+                })
+                .collect();
+
+            let mut naive = HashSet::new();
+            for &(a, a_box) in &boxes {
+                for &(b, b_box) in &boxes {
+                    if a < b && overlaps(a_box, b_box) {
+                        naive.insert((a, b));
+                    }
+                }
+            }
+
+            let mut grid = SpatialGrid::new(64.0);
+            for &(entity, aabb) in &boxes {
+                grid.insert(entity, aabb);
+            }
+            let mut broad_phase = HashSet::new();
+            for &(a, a_box) in &boxes {
+                for b in grid.query_region(a_box) {
+                    let b_box = boxes[b.index() as usize].1;
+                    if a < b && overlaps(a_box, b_box) {
+                        broad_phase.insert((a, b));
+                    }
+                }
+            }
+            assert_eq!(broad_phase, naive);
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Tell apart entities that share a sprite sheet by tinting them.
+
+use bevy::prelude::*;
+
+use crate::demo::damage_flash::DamageFlash;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<SpriteVariant>();
+    // Apply new tints before the first frame is rendered.
+    app.add_systems(PostUpdate, apply_sprite_variants);
+}
+
+/// Tints the [`Sprite`] of its entity, so e.g. several players can share one
+/// texture. Color effects such as [`DamageFlash`] restore the tint afterwards.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component)]
+pub struct SpriteVariant {
+    pub tint: Color,
+}
+
+impl SpriteVariant {
+    /// Distinct tints for up to four players. The first one leaves the sprite as is.
+    pub const PALETTE: [Color; 4] = [
+        Color::WHITE,
+        Color::srgb(0.5, 0.8, 1.0),
+        Color::srgb(0.6, 1.0, 0.5),
+        Color::srgb(1.0, 0.7, 1.0),
+    ];
+
+    /// The variant for the player with the given index, counting from 0.
+    /// Wraps around if there are more players than [`Self::PALETTE`] colors.
+    pub fn for_player(index: usize) -> Self {
+        Self {
+            tint: Self::PALETTE[index % Self::PALETTE.len()],
+        }
+    }
+}
+
+fn apply_sprite_variants(
+    mut variant_query: Query<
+        (&SpriteVariant, &mut Sprite, Option<&mut DamageFlash>),
+        Changed<SpriteVariant>,
+    >,
+) {
+    for (variant, mut sprite, flash) in &mut variant_query {
+        match flash {
+            // Don't interrupt the flash, but restore the new tint once it's over.
+            Some(mut flash) if flash.is_flashing() => flash.set_restore_color(variant.tint),
+            _ => sprite.color = variant.tint,
+        }
+    }
+}
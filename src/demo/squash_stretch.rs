@@ -0,0 +1,83 @@
+//! Squash a sprite when it stops and stretch it when it starts moving.
+
+use bevy::prelude::*;
+
+use crate::{
+    accessibility::AccessibilitySettings,
+    demo::movement::{apply_movement, MovementController},
+    AppSet,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<SquashStretch>();
+    app.add_systems(
+        Update,
+        apply_squash_stretch
+            .after(apply_movement)
+            .in_set(AppSet::Update),
+    );
+}
+
+/// The most an axis is scaled up or down, as a fraction of the rest pose.
+const MAX_DEFORMATION: f32 = 0.4;
+
+/// Deform the [`Transform`] scale based on changes in the speed of the
+/// [`MovementController`], easing back to the rest pose afterwards.
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component)]
+pub struct SquashStretch {
+    /// How much to deform for a change from standing still to full speed.
+    /// For example, 0.1 stretches by 10%.
+    pub intensity: f32,
+    /// How quickly to return to the rest pose. Higher is faster.
+    pub recovery: f32,
+    /// The scale when not deformed.
+    pub rest_scale: Vec2,
+    /// Positive when stretched, negative when squashed.
+    deformation: f32,
+    previous_speed: f32,
+}
+
+impl SquashStretch {
+    pub fn new(intensity: f32, recovery: f32, rest_scale: Vec2) -> Self {
+        Self {
+            intensity,
+            recovery,
+            rest_scale,
+            deformation: 0.0,
+            previous_speed: 0.0,
+        }
+    }
+}
+
+fn apply_squash_stretch(
+    time: Res<Time>,
+    accessibility: Res<AccessibilitySettings>,
+    mut query: Query<(&MovementController, &mut SquashStretch, &mut Transform)>,
+) {
+    for (controller, mut squash_stretch, mut transform) in &mut query {
+        let speed = controller.max_speed * controller.intent.length();
+        let change = if controller.max_speed > 0.0 {
+            (speed - squash_stretch.previous_speed) / controller.max_speed
+        } else {
+            0.0
+        };
+        squash_stretch.previous_speed = speed;
+        // With reduced motion, only ease back to the rest pose.
+        let intensity = if accessibility.reduced_motion {
+            0.0
+        } else {
+            squash_stretch.intensity
+        };
+
+        // Ease back towards the rest pose, independently of the frame rate.
+        let decay = (-squash_stretch.recovery * time.delta_seconds()).exp();
+        squash_stretch.deformation = (squash_stretch.deformation * decay + change * intensity)
+            .clamp(-MAX_DEFORMATION, MAX_DEFORMATION);
+
+        // Stretch upwards when speeding up, and squash flat when slowing down.
+        let deformation = squash_stretch.deformation;
+        let scale = squash_stretch.rest_scale * Vec2::new(1.0 - deformation, 1.0 + deformation);
+        transform.scale = scale.extend(transform.scale.z);
+    }
+}
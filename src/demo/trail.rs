@@ -0,0 +1,124 @@
+//! Leave fading afterimages of a sprite behind, e.g. while dashing.
+//!
+//! Afterimages are disabled with [`AccessibilitySettings::reduced_motion`](crate::accessibility::AccessibilitySettings::reduced_motion).
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    accessibility::reduced_motion,
+    screens::Screen,
+    tween::{OnTweenComplete, Tween, TweenTarget},
+    AppSet,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<(Trail, Afterimage)>();
+    app.add_systems(
+        Update,
+        (
+            tick_trails.in_set(AppSet::TickTimers),
+            spawn_afterimages
+                .run_if(not(reduced_motion))
+                .in_set(AppSet::Update),
+        ),
+    );
+}
+
+/// The most afterimages that can exist at once across all trails.
+const MAX_AFTERIMAGES: usize = 32;
+
+/// How opaque an afterimage is when it's spawned.
+const AFTERIMAGE_ALPHA: f32 = 0.5;
+
+/// Spawns copies of the entity's sprite at its position while [`Self::active`].
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component)]
+pub struct Trail {
+    /// Whether to spawn afterimages. Set this while e.g. dashing.
+    pub active: bool,
+    /// How long each afterimage takes to fade out.
+    pub fade_duration: Duration,
+    timer: Timer,
+}
+
+impl Trail {
+    pub fn new(spawn_interval: Duration, fade_duration: Duration) -> Self {
+        Self {
+            active: false,
+            fade_duration,
+            timer: Timer::new(spawn_interval, TimerMode::Repeating),
+        }
+    }
+}
+
+/// A fading copy of a sprite. It has no collider, so nothing can interact with it.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Component)]
+pub struct Afterimage;
+
+fn tick_trails(time: Res<Time>, mut trail_query: Query<&mut Trail>) {
+    for mut trail in &mut trail_query {
+        if trail.active {
+            trail.timer.tick(time.delta());
+        } else {
+            // Spawn the first afterimage as soon as the trail becomes active.
+            let duration = trail.timer.duration();
+            trail.timer.set_elapsed(duration);
+        }
+    }
+}
+
+fn spawn_afterimages(
+    mut commands: Commands,
+    trail_query: Query<(
+        &Trail,
+        &GlobalTransform,
+        &Sprite,
+        &Handle<Image>,
+        Option<&TextureAtlas>,
+    )>,
+    afterimage_query: Query<(), With<Afterimage>>,
+) {
+    let mut available = MAX_AFTERIMAGES.saturating_sub(afterimage_query.iter().count());
+    for (trail, transform, sprite, texture, atlas) in &trail_query {
+        if available == 0 {
+            break;
+        }
+        if !trail.active || !trail.timer.finished() {
+            continue;
+        }
+        available -= 1;
+
+        // Draw afterimages just behind the original.
+        let mut transform = transform.compute_transform();
+        transform.translation.z -= 0.01;
+        let color = sprite.color.with_alpha(AFTERIMAGE_ALPHA);
+        let mut afterimage = commands.spawn((
+            Name::new("Afterimage"),
+            Afterimage,
+            SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    ..sprite.clone()
+                },
+                texture: texture.clone(),
+                transform,
+                ..default()
+            },
+            Tween::new(
+                TweenTarget::Color {
+                    from: color,
+                    to: color.with_alpha(0.0),
+                },
+                trail.fade_duration,
+            )
+            .with_on_complete(OnTweenComplete::Despawn),
+            StateScoped(Screen::Gameplay),
+        ));
+        if let Some(atlas) = atlas {
+            afterimage.insert(atlas.clone());
+        }
+    }
+}
@@ -0,0 +1,424 @@
+//! An in-game developer console (press \` aka backtick to toggle).
+//!
+//! Submitted lines are split on whitespace. The first word selects a command
+//! from the [`ConsoleCommands`] registry, and the remaining words are passed to
+//! it as arguments. Register your own commands with
+//! [`RegisterConsoleCommand::register_console_command`].
+
+use std::collections::BTreeMap;
+
+use bevy::{
+    ecs::{system::RunSystemOnce as _, world::Command},
+    input::{
+        keyboard::{Key, KeyboardInput},
+        ButtonState, InputSystem,
+    },
+    prelude::*,
+};
+
+use crate::{
+    audio::AudioSettings,
+    config::GameConfig,
+    demo::{
+        inventory::{Inventory, InventoryChanged},
+        key_bindings::{reset_key_bindings, Action, KeyBindings},
+        movement::MovementController,
+        player::{Player, PlayerAssets, SpawnPlayer},
+        playtime::PlaytimeStats,
+    },
+    save::{
+        current_save_data,
+        share::{export_save, import_save},
+        LoadedGame,
+    },
+    screens::{all_assets_loaded, Screen},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ConsoleState>();
+    app.init_resource::<ConsoleCommands>();
+    app.add_systems(Startup, spawn_console);
+
+    // Read keyboard input before any other system gets to see it.
+    app.add_systems(PreUpdate, handle_console_input.after(InputSystem));
+    app.add_systems(
+        Update,
+        (
+            run_console_commands,
+            (update_console_visibility, update_console_text)
+                .run_if(resource_changed::<ConsoleState>),
+        )
+            .chain(),
+    );
+
+    app.register_console_command("help", "List all commands", help)
+        .register_console_command("spawn", "spawn player", spawn)
+        .register_console_command(
+            "state",
+            "state <splash|loading|title|credits|saves|settings|playing>",
+            state,
+        )
+        .register_console_command("set", "set <speed|volume> <value>", set)
+        .register_console_command("give", "give <item> [count]", give)
+        .register_console_command("stats", "stats reset", stats)
+        .register_console_command("bind", "bind <action> <key> | bind reset", bind)
+        .register_console_command("export", "Print the current run as a save string", export)
+        .register_console_command("import", "import <save string>", import);
+}
+
+const TOGGLE_KEY: KeyCode = KeyCode::Backquote;
+
+/// How many lines of output to keep.
+const MAX_HISTORY: usize = 12;
+
+/// A console command. Receives the arguments following the command name and
+/// returns a line of output on success or an error message on failure.
+pub type ConsoleCommandFn = fn(&mut World, &[&str]) -> Result<String, String>;
+
+struct ConsoleCommand {
+    help: &'static str,
+    run: ConsoleCommandFn,
+}
+
+/// The registry of available console commands by name.
+#[derive(Resource, Default)]
+pub struct ConsoleCommands(BTreeMap<&'static str, ConsoleCommand>);
+
+pub trait RegisterConsoleCommand {
+    /// Make a command available in the developer console.
+    /// Registering a command with an existing name replaces it.
+    fn register_console_command(
+        &mut self,
+        name: &'static str,
+        help: &'static str,
+        run: ConsoleCommandFn,
+    ) -> &mut Self;
+}
+
+impl RegisterConsoleCommand for App {
+    fn register_console_command(
+        &mut self,
+        name: &'static str,
+        help: &'static str,
+        run: ConsoleCommandFn,
+    ) -> &mut Self {
+        self.world_mut()
+            .get_resource_or_insert_with(ConsoleCommands::default)
+            .0
+            .insert(name, ConsoleCommand { help, run });
+        self
+    }
+}
+
+#[derive(Resource, Default)]
+struct ConsoleState {
+    open: bool,
+    /// The line currently being typed.
+    input: String,
+    /// Lines that were submitted but not run yet.
+    submitted: Vec<String>,
+    /// Previous commands and their output.
+    history: Vec<String>,
+}
+
+impl ConsoleState {
+    fn push_history(&mut self, line: String) {
+        self.history.push(line);
+        let excess = self.history.len().saturating_sub(MAX_HISTORY);
+        self.history.drain(..excess);
+    }
+}
+
+#[derive(Component)]
+struct ConsoleUi;
+
+#[derive(Component)]
+struct ConsoleText;
+
+fn spawn_console(mut commands: Commands) {
+    commands
+        .spawn((
+            Name::new("Dev Console"),
+            ConsoleUi,
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    padding: UiRect::all(Val::Px(8.0)),
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::BLACK.with_alpha(0.8)),
+                visibility: Visibility::Hidden,
+                z_index: ZIndex::Global(i32::MAX),
+                ..default()
+            },
+        ))
+        .with_children(|children| {
+            children.spawn((
+                Name::new("Dev Console Text"),
+                ConsoleText,
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 18.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ),
+            ));
+        });
+}
+
+/// Toggle the console and, while it is open, type into it.
+/// Keyboard input is consumed so that gameplay doesn't react to typing.
+fn handle_console_input(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut keys: ResMut<ButtonInput<KeyCode>>,
+    mut state: ResMut<ConsoleState>,
+) {
+    if keys.just_pressed(TOGGLE_KEY) {
+        state.open = !state.open;
+        keys.reset_all();
+        keyboard_events.clear();
+        return;
+    }
+    if !state.open {
+        keyboard_events.clear();
+        return;
+    }
+
+    for event in keyboard_events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        match &event.logical_key {
+            Key::Enter => {
+                let line = std::mem::take(&mut state.input);
+                if !line.trim().is_empty() {
+                    state.submitted.push(line);
+                }
+            }
+            Key::Backspace => {
+                state.input.pop();
+            }
+            Key::Escape => state.open = false,
+            Key::Space => state.input.push(' '),
+            Key::Character(text) => state.input.push_str(text),
+            _ => {}
+        }
+    }
+    keys.reset_all();
+}
+
+fn run_console_commands(world: &mut World) {
+    // Avoid triggering change detection if there's nothing to run.
+    if world.resource::<ConsoleState>().submitted.is_empty() {
+        return;
+    }
+    let submitted = std::mem::take(&mut world.resource_mut::<ConsoleState>().submitted);
+
+    for line in submitted {
+        let words = line.split_whitespace().collect::<Vec<_>>();
+        let run = world
+            .resource::<ConsoleCommands>()
+            .0
+            .get(words[0])
+            .map(|command| command.run);
+        let output = match run {
+            Some(run) => run(world, &words[1..]),
+            None => Err(format!("Unknown command `{}`, try `help`", words[0])),
+        };
+        let output = output.unwrap_or_else(|error| format!("error: {error}"));
+
+        let mut state = world.resource_mut::<ConsoleState>();
+        state.push_history(format!("> {line}"));
+        if !output.is_empty() {
+            state.push_history(output);
+        }
+    }
+}
+
+fn update_console_visibility(
+    state: Res<ConsoleState>,
+    mut ui_query: Query<&mut Visibility, With<ConsoleUi>>,
+) {
+    for mut visibility in &mut ui_query {
+        *visibility = if state.open {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+}
+
+fn update_console_text(
+    state: Res<ConsoleState>,
+    mut text_query: Query<&mut Text, With<ConsoleText>>,
+) {
+    let mut value = state.history.join("\n");
+    if !value.is_empty() {
+        value.push('\n');
+    }
+    value.push_str(&format!("> {}_", state.input));
+
+    for mut text in &mut text_query {
+        text.sections[0].value.clone_from(&value);
+    }
+}
+
+fn help(world: &mut World, _args: &[&str]) -> Result<String, String> {
+    let commands = world.resource::<ConsoleCommands>();
+    Ok(commands
+        .0
+        .iter()
+        .map(|(name, command)| format!("{name}: {}", command.help))
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+fn spawn(world: &mut World, args: &[&str]) -> Result<String, String> {
+    match args {
+        ["player"] => {
+            if !world.contains_resource::<PlayerAssets>() {
+                return Err("Player assets are not loaded yet".to_string());
+            }
+            let max_speed = world.resource::<GameConfig>().player.max_speed;
+            SpawnPlayer {
+                max_speed,
+                position: Vec2::ZERO,
+            }
+            .apply(world);
+            Ok("Spawned player".to_string())
+        }
+        _ => Err("Usage: spawn player".to_string()),
+    }
+}
+
+fn state(world: &mut World, args: &[&str]) -> Result<String, String> {
+    let screen = match args {
+        ["splash"] => Screen::Splash,
+        ["loading"] => Screen::Loading,
+        ["title"] => Screen::Title,
+        ["credits"] => Screen::Credits,
+        ["saves"] => Screen::Saves,
+        ["settings"] => Screen::Settings,
+        ["playing" | "gameplay"] => Screen::Gameplay,
+        _ => {
+            return Err(
+                "Usage: state <splash|loading|title|credits|saves|settings|playing>".to_string(),
+            )
+        }
+    };
+    // Screens after the loading screen need its assets, e.g. to spawn the level.
+    if !matches!(screen, Screen::Splash | Screen::Loading)
+        && !world.run_system_once(all_assets_loaded)
+    {
+        return Err("Assets are not loaded yet".to_string());
+    }
+    let message = format!("Entering {screen:?}");
+    world.resource_mut::<NextState<Screen>>().set(screen);
+    Ok(message)
+}
+
+fn set(world: &mut World, args: &[&str]) -> Result<String, String> {
+    match args {
+        ["speed", value] => {
+            let speed = value
+                .parse::<f32>()
+                .map_err(|_| format!("`{value}` is not a number"))?;
+            let mut query = world.query_filtered::<&mut MovementController, With<Player>>();
+            for mut controller in query.iter_mut(world) {
+                controller.max_speed = speed;
+            }
+            Ok(format!("Set player speed to {speed}"))
+        }
+        ["volume", value] => {
+            let volume = value
+                .parse::<f32>()
+                .map_err(|_| format!("`{value}` is not a number"))?
+                .clamp(0.0, 1.0);
+            world.resource_mut::<AudioSettings>().global_volume = volume;
+            Ok(format!("Set volume to {volume}"))
+        }
+        _ => Err("Usage: set <speed|volume> <value>".to_string()),
+    }
+}
+
+fn give(world: &mut World, args: &[&str]) -> Result<String, String> {
+    let (item, count) = match args {
+        [item] => (*item, 1),
+        [item, count] => (
+            *item,
+            count
+                .parse::<u32>()
+                .map_err(|_| format!("`{count}` is not a count"))?,
+        ),
+        _ => return Err("Usage: give <item> [count]".to_string()),
+    };
+
+    let mut query = world.query_filtered::<(Entity, &mut Inventory), With<Player>>();
+    let mut changed = Vec::new();
+    let mut added = 0;
+    for (entity, mut inventory) in query.iter_mut(world) {
+        let added_here = inventory.add(item, count);
+        if added_here > 0 {
+            added += added_here;
+            changed.push(InventoryChanged {
+                entity,
+                item: item.to_string(),
+            });
+        }
+    }
+    world.send_event_batch(changed);
+    Ok(format!("Gave {added} {item}"))
+}
+
+fn bind(world: &mut World, args: &[&str]) -> Result<String, String> {
+    match args {
+        ["reset"] => {
+            reset_key_bindings(world);
+            Ok("Reset key bindings to defaults".to_string())
+        }
+        [action, key] => {
+            // Parse the names used in the settings file, e.g. `MoveUp` and `KeyW`.
+            let action = ron::de::from_str::<Action>(action).map_err(|_| {
+                format!("`{action}` is not an action, try one of {:?}", Action::ALL)
+            })?;
+            let key = ron::de::from_str::<KeyCode>(key)
+                .map_err(|_| format!("`{key}` is not a key code, e.g. `KeyW` or `Space`"))?;
+            world.resource_mut::<KeyBindings>().bind(action, key);
+            Ok(format!("Bound {action:?} to {key:?}"))
+        }
+        _ => Err("Usage: bind <action> <key> | bind reset".to_string()),
+    }
+}
+
+fn stats(world: &mut World, args: &[&str]) -> Result<String, String> {
+    match args {
+        ["reset"] => {
+            world.resource_mut::<PlaytimeStats>().reset();
+            Ok("Reset playtime stats".to_string())
+        }
+        _ => Err("Usage: stats reset".to_string()),
+    }
+}
+
+fn export(world: &mut World, _args: &[&str]) -> Result<String, String> {
+    let data = world.run_system_once(current_save_data);
+    let text = export_save(&data);
+    // Log it too, since it's easier to copy from the terminal.
+    info!("Exported save: {text}");
+    Ok(text)
+}
+
+fn import(world: &mut World, args: &[&str]) -> Result<String, String> {
+    let [text] = args else {
+        return Err("Usage: import <save string>".to_string());
+    };
+    let data = import_save(text)?;
+    world.insert_resource(LoadedGame(data));
+    world
+        .resource_mut::<NextState<Screen>>()
+        .set(Screen::Gameplay);
+    Ok("Imported save".to_string())
+}
@@ -0,0 +1,62 @@
+//! Warn about frames that take longer than a budget, to catch hitches during playtests.
+//!
+//! Per-system timings are not available at runtime. To find out which system
+//! caused a hitch, run the game with a profiler, e.g. with the `bevy/trace_tracy` feature.
+
+use std::time::Duration;
+
+use bevy::{ecs::entity::Entities, prelude::*};
+
+use super::console::RegisterConsoleCommand;
+use crate::screens::Screen;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<FrameBudget>();
+    app.add_systems(Last, warn_over_budget);
+
+    app.register_console_command("budget", "budget <milliseconds>", budget);
+}
+
+/// Frames taking longer than this are logged as warnings.
+#[derive(Resource, Debug)]
+pub struct FrameBudget(pub Duration);
+
+impl Default for FrameBudget {
+    fn default() -> Self {
+        // About 30 FPS.
+        Self(Duration::from_millis(33))
+    }
+}
+
+fn warn_over_budget(
+    time: Res<Time<Real>>,
+    budget: Res<FrameBudget>,
+    screen: Option<Res<State<Screen>>>,
+    entities: &Entities,
+) {
+    let frame_time = time.delta();
+    if frame_time <= budget.0 {
+        return;
+    }
+    let screen = screen.map(|screen| screen.get().clone());
+    warn!(
+        "Frame took {:.1}ms (budget {:.1}ms) on screen {screen:?} with {} entities",
+        frame_time.as_secs_f64() * 1000.0,
+        budget.0.as_secs_f64() * 1000.0,
+        entities.len(),
+    );
+}
+
+fn budget(world: &mut World, args: &[&str]) -> Result<String, String> {
+    let [millis] = args else {
+        return Err("Usage: budget <milliseconds>".to_string());
+    };
+    let millis = millis
+        .parse::<f64>()
+        .map_err(|_| format!("`{millis}` is not a number"))?;
+    if !millis.is_finite() || millis <= 0.0 {
+        return Err("The budget must be positive".to_string());
+    }
+    world.resource_mut::<FrameBudget>().0 = Duration::from_secs_f64(millis / 1000.0);
+    Ok(format!("Set frame budget to {millis}ms"))
+}
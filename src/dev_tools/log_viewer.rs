@@ -0,0 +1,258 @@
+//! An in-game overlay showing recent log messages (press F10 to toggle).
+//!
+//! This is especially useful on web, where the browser console is awkward to use.
+//! Filter the shown messages from the developer console with
+//! `log level <error|warn|info|debug|trace>` and `log module [prefix]`.
+
+use std::{
+    collections::VecDeque,
+    fmt::Write as _,
+    sync::{Arc, Mutex},
+};
+
+use bevy::{
+    input::common_conditions::input_just_pressed,
+    log::{tracing_subscriber::Layer, BoxedLayer},
+    prelude::*,
+};
+use tracing::{
+    field::{Field, Visit},
+    Level, Subscriber,
+};
+
+use super::console::RegisterConsoleCommand;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<LogFilter>();
+    app.add_systems(Startup, spawn_log_viewer);
+    app.add_systems(
+        Update,
+        (
+            toggle_log_viewer.run_if(input_just_pressed(TOGGLE_KEY)),
+            update_log_viewer,
+        )
+            .chain(),
+    );
+
+    app.register_console_command("log", "log level <level> | log module [prefix]", log);
+}
+
+const TOGGLE_KEY: KeyCode = KeyCode::F10;
+
+/// How many log messages to keep around.
+const BUFFER_CAPACITY: usize = 500;
+
+/// How many log messages to show at once.
+const SHOWN_ENTRIES: usize = 20;
+
+/// Create a [`Layer`] that captures log messages into the [`LogBuffer`] resource.
+/// This should be passed to [`LogPlugin::custom_layer`](bevy::log::LogPlugin::custom_layer).
+pub fn capture_log_layer(app: &mut App) -> Option<BoxedLayer> {
+    let buffer = LogBuffer::default();
+    app.insert_resource(buffer.clone());
+    Some(Box::new(CaptureLayer(buffer)))
+}
+
+/// Recent log messages, shared with the logging backend.
+#[derive(Resource, Clone, Default)]
+struct LogBuffer(Arc<Mutex<LogEntries>>);
+
+#[derive(Default)]
+struct LogEntries {
+    entries: VecDeque<LogEntry>,
+    /// The total number of messages captured so far.
+    /// Used to detect whether new messages came in.
+    count: usize,
+}
+
+struct LogEntry {
+    level: Level,
+    /// The module the message came from.
+    target: String,
+    message: String,
+}
+
+struct CaptureLayer(LogBuffer);
+
+impl<S: Subscriber> Layer<S> for CaptureLayer {
+    fn on_event(
+        &self,
+        event: &tracing::Event<'_>,
+        _ctx: bevy::log::tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        let metadata = event.metadata();
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        let Ok(mut log) = self.0 .0.lock() else {
+            return;
+        };
+        if log.entries.len() == BUFFER_CAPACITY {
+            log.entries.pop_front();
+        }
+        log.entries.push_back(LogEntry {
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            message: visitor.0,
+        });
+        log.count += 1;
+    }
+}
+
+/// Formats the fields of a log event into a single line.
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        if field.name() == "message" {
+            let _ = write!(self.0, "{value:?}");
+        } else {
+            let _ = write!(self.0, "{}={value:?}", field.name());
+        }
+    }
+}
+
+/// Which log messages to show in the overlay.
+#[derive(Resource)]
+struct LogFilter {
+    /// The least severe level to show.
+    level: Level,
+    /// Only show messages from modules starting with this prefix.
+    module: Option<String>,
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        Self {
+            level: Level::INFO,
+            module: None,
+        }
+    }
+}
+
+impl LogFilter {
+    fn matches(&self, entry: &LogEntry) -> bool {
+        // More severe levels compare as smaller.
+        entry.level <= self.level
+            && self
+                .module
+                .as_ref()
+                .map_or(true, |module| entry.target.starts_with(module.as_str()))
+    }
+}
+
+#[derive(Component)]
+struct LogViewer {
+    /// The [`LogEntries::count`] that is currently displayed.
+    shown_count: usize,
+}
+
+fn spawn_log_viewer(mut commands: Commands) {
+    commands
+        .spawn((
+            Name::new("Log Viewer"),
+            NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.0),
+                    padding: UiRect::all(Val::Px(8.0)),
+                    bottom: Val::Px(0.0),
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                background_color: BackgroundColor(Color::BLACK.with_alpha(0.8)),
+                visibility: Visibility::Hidden,
+                z_index: ZIndex::Global(i32::MAX - 1),
+                ..default()
+            },
+        ))
+        .with_children(|children| {
+            children.spawn((
+                Name::new("Log Viewer Text"),
+                LogViewer { shown_count: 0 },
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font_size: 14.0,
+                        color: Color::WHITE,
+                        ..default()
+                    },
+                ),
+            ));
+        });
+}
+
+fn toggle_log_viewer(
+    mut viewer_query: Query<&Parent, With<LogViewer>>,
+    mut visibility_query: Query<&mut Visibility>,
+) {
+    for parent in &mut viewer_query {
+        let Ok(mut visibility) = visibility_query.get_mut(parent.get()) else {
+            continue;
+        };
+        *visibility = match *visibility {
+            Visibility::Hidden => Visibility::Inherited,
+            _ => Visibility::Hidden,
+        };
+    }
+}
+
+fn update_log_viewer(
+    buffer: Option<Res<LogBuffer>>,
+    filter: Res<LogFilter>,
+    mut viewer_query: Query<(&mut LogViewer, &mut Text, &ViewVisibility)>,
+) {
+    // The buffer only exists if `capture_log_layer` was used.
+    let Some(buffer) = buffer else {
+        return;
+    };
+    let Ok(log) = buffer.0.lock() else {
+        return;
+    };
+
+    for (mut viewer, mut text, visibility) in &mut viewer_query {
+        if !visibility.get() || (viewer.shown_count == log.count && !filter.is_changed()) {
+            continue;
+        }
+        viewer.shown_count = log.count;
+
+        let mut shown = log
+            .entries
+            .iter()
+            .rev()
+            .filter(|entry| filter.matches(entry))
+            .take(SHOWN_ENTRIES)
+            .collect::<Vec<_>>();
+        shown.reverse();
+
+        let value = &mut text.sections[0].value;
+        value.clear();
+        for entry in shown {
+            let _ = writeln!(value, "{} {}: {}", entry.level, entry.target, entry.message);
+        }
+    }
+}
+
+fn log(world: &mut World, args: &[&str]) -> Result<String, String> {
+    let mut filter = world.resource_mut::<LogFilter>();
+    match args {
+        ["level", level] => {
+            filter.level = level
+                .parse()
+                .map_err(|_| format!("`{level}` is not a log level"))?;
+            Ok(format!("Showing logs at level {} and above", filter.level))
+        }
+        ["module", module] => {
+            filter.module = Some(module.to_string());
+            Ok(format!("Showing logs from `{module}`"))
+        }
+        ["module"] => {
+            filter.module = None;
+            Ok("Showing logs from all modules".to_string())
+        }
+        _ => Err("Usage: log level <level> | log module [prefix]".to_string()),
+    }
+}
@@ -1,5 +1,12 @@
 //! Development tools for the game. This plugin is only enabled in dev builds.
 
+mod console;
+mod frame_budget;
+mod log_viewer;
+mod time_scale;
+
+pub use log_viewer::capture_log_layer;
+
 use bevy::{
     dev_tools::{
         states::log_transitions,
@@ -19,11 +26,23 @@ pub(super) fn plugin(app: &mut App) {
     app.add_plugins(DebugUiPlugin);
     app.add_systems(
         Update,
-        toggle_debug_ui.run_if(input_just_pressed(TOGGLE_KEY)),
+        toggle_debug_ui.run_if(input_just_pressed(TOGGLE_UI_DEBUG_KEY)),
     );
+
+    // Run commands from an in-game console.
+    app.add_plugins(console::plugin);
+
+    // Show recent log messages in-game.
+    app.add_plugins(log_viewer::plugin);
+
+    // Warn about frames that take too long.
+    app.add_plugins(frame_budget::plugin);
+
+    // Slow down, pause, and step through time.
+    app.add_plugins(time_scale::plugin);
 }
 
-const TOGGLE_KEY: KeyCode = KeyCode::Backquote;
+const TOGGLE_UI_DEBUG_KEY: KeyCode = KeyCode::F8;
 
 fn toggle_debug_ui(mut options: ResMut<UiDebugOptions>) {
     options.toggle();
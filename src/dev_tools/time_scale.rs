@@ -0,0 +1,88 @@
+//! Dev controls for slowing down, pausing, and stepping through time.
+//!
+//! These act on [`Time<Virtual>`], so systems running in `FixedUpdate` respect
+//! them as well.
+
+use bevy::{input::common_conditions::input_just_pressed, prelude::*, time::TimeSystem};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<FrameStep>();
+
+    // Change the relative speed of time.
+    app.add_systems(
+        Update,
+        (
+            set_relative_speed(0.25).run_if(input_just_pressed(KeyCode::F1)),
+            set_relative_speed(0.5).run_if(input_just_pressed(KeyCode::F2)),
+            set_relative_speed(1.0).run_if(input_just_pressed(KeyCode::F3)),
+            set_relative_speed(2.0).run_if(input_just_pressed(KeyCode::F4)),
+        ),
+    );
+
+    // Pause time and step through it one frame at a time.
+    app.add_systems(
+        Update,
+        (
+            toggle_pause.run_if(input_just_pressed(PAUSE_KEY)),
+            request_frame_step.run_if(input_just_pressed(STEP_KEY)),
+        ),
+    );
+    app.add_systems(First, start_frame_step.after(TimeSystem));
+    app.add_systems(Last, finish_frame_step);
+}
+
+const PAUSE_KEY: KeyCode = KeyCode::F6;
+const STEP_KEY: KeyCode = KeyCode::F7;
+
+fn set_relative_speed(speed: f32) -> impl Fn(ResMut<Time<Virtual>>) {
+    move |mut time| {
+        time.set_relative_speed(speed);
+        info!("Set time scale to {speed}x");
+    }
+}
+
+fn toggle_pause(mut time: ResMut<Time<Virtual>>) {
+    if time.is_paused() {
+        time.unpause();
+        info!("Resumed time");
+    } else {
+        time.pause();
+        info!("Paused time");
+    }
+}
+
+/// Progress of a single-frame step while time is paused.
+#[derive(Resource, Debug, Default, PartialEq, Eq)]
+enum FrameStep {
+    #[default]
+    Idle,
+    /// Time was unpaused and will advance on the next frame.
+    Requested,
+    /// Time is advancing during the current frame.
+    Stepping,
+}
+
+/// Pause time if it's running, or advance it by a single frame if it's paused.
+fn request_frame_step(mut time: ResMut<Time<Virtual>>, mut step: ResMut<FrameStep>) {
+    if time.is_paused() {
+        time.unpause();
+        *step = FrameStep::Requested;
+    } else {
+        time.pause();
+        info!("Paused time");
+    }
+}
+
+/// Runs right after [`Time<Virtual>`] has been advanced for this frame.
+fn start_frame_step(mut step: ResMut<FrameStep>) {
+    if *step == FrameStep::Requested {
+        *step = FrameStep::Stepping;
+    }
+}
+
+fn finish_frame_step(mut time: ResMut<Time<Virtual>>, mut step: ResMut<FrameStep>) {
+    if *step == FrameStep::Stepping {
+        time.pause();
+        *step = FrameStep::Idle;
+    }
+}
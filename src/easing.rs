@@ -0,0 +1,93 @@
+//! Easing curves that shape how an animation progresses over time.
+//!
+//! Every curve maps 0 to 0 and 1 to 1, so they can be swapped freely:
+//!
+//! ```
+//! use bevy_new_2d::easing::Easing;
+//!
+//! for easing in Easing::ALL {
+//!     assert!(easing.apply(0.0).abs() < 1e-5, "{easing:?} doesn't start at 0");
+//!     assert!((easing.apply(1.0) - 1.0).abs() < 1e-5, "{easing:?} doesn't end at 1");
+//! }
+//! ```
+
+use std::f32::consts::TAU;
+
+use bevy::prelude::*;
+
+/// How the progress of an animation is mapped to its value.
+/// See <https://easings.net> for a preview of each curve.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    /// Bounce off the end value a few times before settling.
+    BounceOut,
+    /// Overshoot the end value and spring back and forth around it.
+    ElasticOut,
+    /// Overshoot the end value slightly and then settle back.
+    BackOut,
+}
+
+impl Easing {
+    pub const ALL: [Self; 10] = [
+        Self::Linear,
+        Self::QuadIn,
+        Self::QuadOut,
+        Self::QuadInOut,
+        Self::CubicIn,
+        Self::CubicOut,
+        Self::CubicInOut,
+        Self::BounceOut,
+        Self::ElasticOut,
+        Self::BackOut,
+    ];
+
+    /// Map the progress `t` from 0 to 1 onto the curve.
+    /// `t` is clamped, but the result may leave the range, e.g. for [`Easing::BackOut`].
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => t,
+            Self::QuadIn => t * t,
+            Self::QuadOut => 1.0 - (1.0 - t).powi(2),
+            Self::QuadInOut if t < 0.5 => 2.0 * t * t,
+            Self::QuadInOut => 1.0 - (-2.0 * t + 2.0).powi(2) / 2.0,
+            Self::CubicIn => t.powi(3),
+            Self::CubicOut => 1.0 - (1.0 - t).powi(3),
+            Self::CubicInOut if t < 0.5 => 4.0 * t.powi(3),
+            Self::CubicInOut => 1.0 - (-2.0 * t + 2.0).powi(3) / 2.0,
+            Self::BounceOut => bounce_out(t),
+            Self::ElasticOut if t == 0.0 || t == 1.0 => t,
+            Self::ElasticOut => 2f32.powf(-10.0 * t) * ((t * 10.0 - 0.75) * TAU / 3.0).sin() + 1.0,
+            Self::BackOut => {
+                const C1: f32 = 1.70158;
+                const C3: f32 = C1 + 1.0;
+                1.0 + C3 * (t - 1.0).powi(3) + C1 * (t - 1.0).powi(2)
+            }
+        }
+    }
+}
+
+fn bounce_out(t: f32) -> f32 {
+    const N: f32 = 7.5625;
+    const D: f32 = 2.75;
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
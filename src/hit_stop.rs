@@ -0,0 +1,75 @@
+//! Freeze gameplay for a moment on big hits to make them feel impactful.
+//!
+//! Send a [`HitStop`] event to slow [`Time<Virtual>`] almost to a halt.
+//! Afterwards, the previous relative speed is restored, so this composes with
+//! other changes to the speed of time such as the dev tools' time scale.
+//! Systems using [`Time<Real>`], such as UI [`Tween`](crate::tween::Tween)s,
+//! and input handling keep running.
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_event::<HitStop>();
+    app.add_systems(PostUpdate, (start_hit_stop, tick_hit_stop).chain());
+}
+
+/// The relative speed of time during a hit-stop.
+const HIT_STOP_SPEED: f32 = 0.0;
+
+/// Freeze gameplay for `duration` of real time.
+/// Overlapping hit-stops last as long as the longest one instead of adding up.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HitStop {
+    pub duration: Duration,
+}
+
+/// The hit-stop currently in progress.
+#[derive(Resource, Debug)]
+struct ActiveHitStop {
+    remaining: Duration,
+    /// The relative speed of time before the hit-stop started.
+    restore_speed: f32,
+}
+
+fn start_hit_stop(
+    mut commands: Commands,
+    mut hit_stop_events: EventReader<HitStop>,
+    active: Option<ResMut<ActiveHitStop>>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    let Some(duration) = hit_stop_events.read().map(|event| event.duration).max() else {
+        return;
+    };
+    match active {
+        Some(mut active) => active.remaining = active.remaining.max(duration),
+        None => {
+            commands.insert_resource(ActiveHitStop {
+                remaining: duration,
+                restore_speed: time.relative_speed(),
+            });
+            time.set_relative_speed(HIT_STOP_SPEED);
+        }
+    }
+}
+
+fn tick_hit_stop(
+    mut commands: Commands,
+    real_time: Res<Time<Real>>,
+    active: Option<ResMut<ActiveHitStop>>,
+    mut time: ResMut<Time<Virtual>>,
+) {
+    let Some(mut active) = active else {
+        return;
+    };
+    active.remaining = active.remaining.saturating_sub(real_time.delta());
+    if !active.remaining.is_zero() {
+        return;
+    }
+    // Leave the speed alone if something else changed it in the meantime.
+    if time.relative_speed() == HIT_STOP_SPEED {
+        time.set_relative_speed(active.restore_speed);
+    }
+    commands.remove_resource::<ActiveHitStop>();
+}
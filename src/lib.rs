@@ -1,14 +1,28 @@
+mod accessibility;
 mod asset_tracking;
 pub mod audio;
+mod config;
 mod demo;
 #[cfg(feature = "dev")]
 mod dev_tools;
+pub mod easing;
+pub mod hit_stop;
+pub mod particles;
+pub mod pool;
+pub mod rng;
+pub mod save;
 mod screens;
+mod settings;
+pub mod sim_control;
+pub mod spawn;
 mod theme;
+pub mod tween;
+mod window;
 
 use bevy::{
     asset::AssetMetaCheck,
     audio::{AudioPlugin, Volume},
+    log::LogPlugin,
     prelude::*,
 };
 
@@ -19,8 +33,11 @@ impl Plugin for AppPlugin {
         // Order new `AppStep` variants by adding them here:
         app.configure_sets(
             Update,
-            (AppSet::TickTimers, AppSet::RecordInput, AppSet::Update).chain(),
+            (AppSet::TickTimers, AppSet::RecordInput, AppSet::Update)
+                .chain()
+                .run_if(sim_control::sim_running),
         );
+        app.configure_sets(FixedUpdate, AppSet::Update.run_if(sim_control::sim_running));
 
         // Spawn the main camera.
         app.add_systems(Startup, spawn_camera);
@@ -51,15 +68,32 @@ impl Plugin for AppPlugin {
                         volume: Volume::new(0.3),
                     },
                     ..default()
+                })
+                .set(LogPlugin {
+                    // Capture logs for the in-game log viewer in dev builds.
+                    #[cfg(feature = "dev")]
+                    custom_layer: dev_tools::capture_log_layer,
+                    ..default()
                 }),
         );
 
         // Add other plugins.
         app.add_plugins((
+            accessibility::plugin,
             asset_tracking::plugin,
+            audio::plugin,
+            config::plugin,
             demo::plugin,
+            hit_stop::plugin,
+            particles::plugin,
+            rng::plugin,
+            save::plugin,
             screens::plugin,
+            settings::plugin,
+            sim_control::plugin,
             theme::plugin,
+            tween::plugin,
+            window::plugin,
         ));
 
         // Enable dev tools for dev builds.
@@ -70,7 +104,9 @@ impl Plugin for AppPlugin {
 
 /// High-level groupings of systems for the app in the `Update` schedule.
 /// When adding a new variant, make sure to order it in the `configure_sets`
-/// call above.
+/// call above. Gameplay systems in `FixedUpdate` belong in [`AppSet::Update`].
+///
+/// These sets are paused and stepped by [`SimControl`](sim_control::SimControl).
 #[derive(SystemSet, Debug, Clone, Copy, Eq, PartialEq, Hash, PartialOrd, Ord)]
 enum AppSet {
     /// Tick timers.
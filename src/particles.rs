@@ -0,0 +1,175 @@
+//! Lightweight CPU particles for sparkles, dust, and impacts.
+//!
+//! A [`ParticleEmitter`] spawns particles continuously at its position.
+//! For one-shot effects, trigger a [`ParticleBurst`] with
+//! [`Commands::trigger`]. The total number of particles is capped at
+//! [`MAX_PARTICLES`] to stay cheap on web.
+
+use std::ops::Range;
+
+use bevy::{color::Mix as _, prelude::*};
+
+use crate::{rng::GameRng, AppSet};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Particle>();
+    app.observe(spawn_particle_burst);
+    app.add_systems(
+        Update,
+        (emit_particles, update_particles)
+            .chain()
+            .in_set(AppSet::Update),
+    );
+}
+
+/// The most particles that can be alive at once.
+/// New particles aren't spawned while the cap is reached.
+pub const MAX_PARTICLES: usize = 500;
+
+/// Draw particles in front of characters.
+const PARTICLE_Z: f32 = 5.0;
+
+/// Continuously spawns particles at the position of its entity.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct ParticleEmitter {
+    /// Particles spawned per second. Set to 0 to pause the emitter.
+    pub rate: f32,
+    /// How long each particle lives in seconds.
+    pub lifetime: f32,
+    /// Each particle gets a random velocity in this range, in world units per second.
+    pub velocity_range: Range<Vec2>,
+    /// The color at the start and end of each particle's life.
+    pub color_over_life: (Color, Color),
+    /// The width and height at the start and end of each particle's life.
+    pub size_over_life: (f32, f32),
+    /// Fractional particles carried over to the next frame.
+    accumulated: f32,
+}
+
+impl ParticleEmitter {
+    pub fn new(rate: f32, lifetime: f32, velocity_range: Range<Vec2>) -> Self {
+        Self {
+            rate,
+            lifetime,
+            velocity_range,
+            color_over_life: (Color::WHITE, Color::WHITE.with_alpha(0.0)),
+            size_over_life: (8.0, 2.0),
+            accumulated: 0.0,
+        }
+    }
+
+    pub fn with_color_over_life(mut self, start: Color, end: Color) -> Self {
+        self.color_over_life = (start, end);
+        self
+    }
+
+    pub fn with_size_over_life(mut self, start: f32, end: f32) -> Self {
+        self.size_over_life = (start, end);
+        self
+    }
+
+    fn spawn_particle(&self, commands: &mut Commands, rng: &mut GameRng, position: Vec2) {
+        let Range { start, end } = self.velocity_range;
+        let velocity = Vec2::new(
+            rng.range(start.x.min(end.x)..=start.x.max(end.x)),
+            rng.range(start.y.min(end.y)..=start.y.max(end.y)),
+        );
+        commands.spawn((
+            Name::new("Particle"),
+            Particle {
+                velocity,
+                age: 0.0,
+                lifetime: self.lifetime,
+                color_over_life: self.color_over_life,
+                size_over_life: self.size_over_life,
+            },
+            SpriteBundle {
+                sprite: Sprite {
+                    color: self.color_over_life.0,
+                    custom_size: Some(Vec2::splat(self.size_over_life.0)),
+                    ..default()
+                },
+                transform: Transform::from_translation(position.extend(PARTICLE_Z)),
+                ..default()
+            },
+        ));
+    }
+}
+
+/// Spawn `count` particles at once, e.g. when something is hit or collected.
+/// The [`ParticleEmitter::rate`] of `emitter` is ignored.
+#[derive(Event, Debug, Clone)]
+pub struct ParticleBurst {
+    pub position: Vec2,
+    pub count: usize,
+    pub emitter: ParticleEmitter,
+}
+
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component)]
+pub struct Particle {
+    velocity: Vec2,
+    /// Seconds since the particle was spawned.
+    age: f32,
+    lifetime: f32,
+    color_over_life: (Color, Color),
+    size_over_life: (f32, f32),
+}
+
+fn spawn_particle_burst(
+    trigger: Trigger<ParticleBurst>,
+    mut commands: Commands,
+    mut rng: ResMut<GameRng>,
+    particle_query: Query<(), With<Particle>>,
+) {
+    let burst = trigger.event();
+    let available = MAX_PARTICLES.saturating_sub(particle_query.iter().count());
+    for _ in 0..burst.count.min(available) {
+        burst
+            .emitter
+            .spawn_particle(&mut commands, &mut rng, burst.position);
+    }
+}
+
+fn emit_particles(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut rng: ResMut<GameRng>,
+    mut emitter_query: Query<(&mut ParticleEmitter, &GlobalTransform)>,
+    particle_query: Query<(), With<Particle>>,
+) {
+    let mut available = MAX_PARTICLES.saturating_sub(particle_query.iter().count());
+    for (mut emitter, transform) in &mut emitter_query {
+        emitter.accumulated += emitter.rate * time.delta_seconds();
+        let count = emitter.accumulated as usize;
+        emitter.accumulated -= count as f32;
+
+        let position = transform.translation().xy();
+        for _ in 0..count.min(available) {
+            emitter.spawn_particle(&mut commands, &mut rng, position);
+        }
+        available = available.saturating_sub(count);
+    }
+}
+
+fn update_particles(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut particle_query: Query<(Entity, &mut Particle, &mut Transform, &mut Sprite)>,
+) {
+    let dt = time.delta_seconds();
+    for (entity, mut particle, mut transform, mut sprite) in &mut particle_query {
+        particle.age += dt;
+        if particle.age >= particle.lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        transform.translation += (particle.velocity * dt).extend(0.0);
+        let t = particle.age / particle.lifetime;
+        let (start_color, end_color) = particle.color_over_life;
+        sprite.color = Color::from(start_color.to_linear().mix(&end_color.to_linear(), t));
+        let (start_size, end_size) = particle.size_over_life;
+        sprite.custom_size = Some(Vec2::splat(start_size.lerp(end_size, t)));
+    }
+}
@@ -0,0 +1,95 @@
+//! Recycle entities that are spawned and despawned often, like projectiles.
+//!
+//! Instead of despawning an entity, [`Pool::release`] hides it and marks it as
+//! [`Pooled`]. The next [`Pool::acquire`] hands it out again, so that no new
+//! entity needs to be spawned. For example, firing and expiring many bullets
+//! only ever uses a single entity:
+//!
+//! ```
+//! use bevy::{ecs::world::CommandQueue, prelude::*};
+//! use bevy_new_2d::pool::Pool;
+//!
+//! #[derive(Component)]
+//! struct Bullet;
+//!
+//! let mut world = World::new();
+//! let mut queue = CommandQueue::default();
+//! let mut pool = Pool::<Bullet>::default();
+//! for _ in 0..100 {
+//!     let mut commands = Commands::new(&mut queue, &world);
+//!     // Fire a bullet.
+//!     let bullet = match pool.acquire(&mut commands) {
+//!         Some(mut entity) => entity.insert(Bullet).id(),
+//!         None => commands.spawn(Bullet).id(),
+//!     };
+//!     // Expire it.
+//!     let mut entity = commands.entity(bullet);
+//!     entity.remove::<Bullet>();
+//!     pool.release(entity);
+//!     queue.apply(&mut world);
+//! }
+//! assert_eq!(world.entities().len(), 1);
+//! ```
+//!
+//! Pooled entities keep all of their components, so remove any that other
+//! systems would react to when releasing them.
+
+use std::marker::PhantomData;
+
+use bevy::{ecs::system::EntityCommands, prelude::*};
+
+/// Marks an entity that is disabled and waiting in a [`Pool`].
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Component)]
+pub struct Pooled;
+
+/// Entities that can be reused for `T`, which is usually the component that
+/// identifies them, e.g. `Pool<Projectile>`.
+#[derive(Resource, Debug)]
+pub struct Pool<T> {
+    free: Vec<Entity>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for Pool<T> {
+    fn default() -> Self {
+        Self {
+            free: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Pool<T> {
+    /// Take an entity out of the pool and show it again, if there is one.
+    /// Insert the components it needs into the returned entity.
+    pub fn acquire<'a>(&mut self, commands: &'a mut Commands) -> Option<EntityCommands<'a>> {
+        // Skip entities that were despawned in the meantime,
+        // e.g. when leaving the screen they were scoped to.
+        let entity = std::iter::from_fn(|| self.free.pop())
+            .find(|&entity| commands.get_entity(entity).is_some())?;
+        let mut entity = commands.entity(entity);
+        entity.remove::<Pooled>().insert(Visibility::Inherited);
+        Some(entity)
+    }
+
+    /// Hide an entity and put it into the pool instead of despawning it.
+    /// Releasing an entity that is already in the pool does nothing.
+    pub fn release(&mut self, mut entity: EntityCommands) {
+        let id = entity.id();
+        if self.free.contains(&id) {
+            return;
+        }
+        entity.insert((Pooled, Visibility::Hidden));
+        self.free.push(id);
+    }
+
+    /// The number of entities waiting in the pool.
+    pub fn len(&self) -> usize {
+        self.free.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.free.is_empty()
+    }
+}
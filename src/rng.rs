@@ -0,0 +1,129 @@
+//! A seedable source of randomness for reproducible gameplay.
+
+use bevy::prelude::*;
+use rand::{
+    distributions::uniform::{SampleRange, SampleUniform},
+    prelude::*,
+    rngs::StdRng,
+};
+
+use crate::config::GameConfig;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<GameRng>();
+    app.add_systems(
+        PreUpdate,
+        apply_config_seed.run_if(resource_changed::<GameConfig>),
+    );
+}
+
+/// The environment variable that overrides the seed of [`GameRng`].
+/// It takes precedence over [`GameConfig::seed`].
+pub const SEED_ENV_VAR: &str = "GAME_SEED";
+
+/// The random number generator that all gameplay randomness should come from.
+///
+/// Two generators created with the same seed produce the same sequence of values,
+/// which makes bugs involving randomness reproducible. For example:
+///
+/// ```
+/// use bevy_new_2d::rng::GameRng;
+///
+/// let steps = ["step1", "step2", "step3", "step4"];
+/// let mut a = GameRng::from_seed(42);
+/// let mut b = GameRng::from_seed(42);
+/// for _ in 0..100 {
+///     assert_eq!(a.pick(&steps), b.pick(&steps));
+///     assert_eq!(a.range(0.8..1.2), b.range(0.8..1.2));
+/// }
+/// ```
+///
+/// The seed is read from the `GAME_SEED` environment variable or
+/// [`GameConfig::seed`] if either is set, and is random otherwise.
+#[derive(Resource, Debug)]
+pub struct GameRng {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl GameRng {
+    pub fn from_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// The seed this generator was created with.
+    /// Log this to be able to reproduce a run later.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Restart the generator from a new seed.
+    pub fn reseed(&mut self, seed: u64) {
+        *self = Self::from_seed(seed);
+    }
+
+    /// Return a random value in the given range, e.g. `rng.range(0.8..1.2)`.
+    pub fn range<T: SampleUniform, R: SampleRange<T>>(&mut self, range: R) -> T {
+        self.rng.gen_range(range)
+    }
+
+    /// Return `true` with the given probability between 0 and 1.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        self.rng.gen_bool(probability)
+    }
+
+    /// Return a random element of the slice, or `None` if it is empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        items.choose(&mut self.rng)
+    }
+
+    /// Shuffle the slice in place.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        items.shuffle(&mut self.rng);
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        let seed = std::env::var(SEED_ENV_VAR)
+            .ok()
+            .and_then(|seed| seed.parse().ok())
+            .unwrap_or_else(random);
+        info!("Using RNG seed {seed}");
+        Self::from_seed(seed)
+    }
+}
+
+/// `GameRng` can be passed to anything in `rand` that needs a generator.
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+fn apply_config_seed(config: Res<GameConfig>, mut rng: ResMut<GameRng>) {
+    if std::env::var(SEED_ENV_VAR).is_ok() {
+        return;
+    }
+    if let Some(seed) = config.seed {
+        if seed != rng.seed() {
+            info!("Using RNG seed {seed}");
+            rng.reseed(seed);
+        }
+    }
+}
@@ -0,0 +1,156 @@
+//! Save the run automatically so that progress isn't lost.
+//!
+//! While playing, the run is saved to [`SaveSlot::Autosave`] every
+//! [`AutosaveSettings::interval`], when the game is paused via
+//! [`SimControl`], and when the app exits. There is only one autosave file,
+//! which is overwritten every time. Nothing is autosaved where saving isn't
+//! supported, see [`SAVING_SUPPORTED`].
+
+use std::time::Duration;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    collect_save_data, save_game, write_save, SaveData, SaveGame, SaveSlot, SAVING_SUPPORTED,
+};
+use crate::{
+    demo::{health::Health, inventory::Inventory, player::Player, run_stats::RunStats},
+    screens::Screen,
+    settings::{RegisterSetting as _, Setting},
+    sim_control::SimControl,
+    AppSet,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_setting::<AutosaveSettings>();
+    app.init_resource::<AutosaveTimer>();
+    app.init_resource::<LastAutosave>();
+    app.add_systems(OnEnter(Screen::Gameplay), reset_autosave);
+    app.add_systems(
+        Update,
+        (
+            tick_autosave_timer.in_set(AppSet::TickTimers),
+            autosave_on_timer.in_set(AppSet::Update).before(save_game),
+            // Pausing stops the `AppSet`s, so this must run outside of them.
+            autosave_on_pause
+                .run_if(resource_changed::<SimControl>)
+                .before(save_game),
+        )
+            .run_if(in_state(Screen::Gameplay).and_then(autosave_enabled)),
+    );
+    app.add_systems(
+        Last,
+        autosave_on_exit.run_if(
+            on_event::<AppExit>()
+                .and_then(in_state(Screen::Gameplay))
+                .and_then(autosave_enabled),
+        ),
+    );
+}
+
+/// How often to autosave. These are persisted as a [`Setting`].
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct AutosaveSettings {
+    pub enabled: bool,
+    /// The time between autosaves while playing.
+    pub interval: Duration,
+}
+
+impl Default for AutosaveSettings {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval: Duration::from_secs(60),
+        }
+    }
+}
+
+impl Setting for AutosaveSettings {
+    const KEY: &'static str = "autosave";
+}
+
+fn autosave_enabled(settings: Res<AutosaveSettings>) -> bool {
+    SAVING_SUPPORTED && settings.enabled
+}
+
+#[derive(Resource, Debug, Default)]
+struct AutosaveTimer(Duration);
+
+/// The data written by the last autosave, to skip saving when nothing changed.
+#[derive(Resource, Debug, Default)]
+struct LastAutosave(Option<SaveData>);
+
+impl LastAutosave {
+    /// Whether `data` differs from the last autosave in a meaningful way.
+    /// The time played always changes, so it's not taken into account.
+    fn has_changed(&self, data: &SaveData) -> bool {
+        let Some(last) = &self.0 else {
+            return true;
+        };
+        let mut last = last.clone();
+        last.run_stats.elapsed = data.run_stats.elapsed;
+        last != *data
+    }
+}
+
+fn reset_autosave(mut timer: ResMut<AutosaveTimer>, mut last: ResMut<LastAutosave>) {
+    timer.0 = Duration::ZERO;
+    last.0 = None;
+}
+
+fn tick_autosave_timer(time: Res<Time>, mut timer: ResMut<AutosaveTimer>) {
+    timer.0 += time.delta();
+}
+
+fn autosave_on_timer(
+    settings: Res<AutosaveSettings>,
+    mut timer: ResMut<AutosaveTimer>,
+    mut last: ResMut<LastAutosave>,
+    run_stats: Res<RunStats>,
+    player_query: Query<(&Transform, &Health, &Inventory), With<Player>>,
+    mut save_events: EventWriter<SaveGame>,
+) {
+    if timer.0 < settings.interval {
+        return;
+    }
+    timer.0 = Duration::ZERO;
+
+    let data = collect_save_data(&run_stats, &player_query);
+    if last.has_changed(&data) {
+        last.0 = Some(data);
+        save_events.send(SaveGame {
+            slot: SaveSlot::Autosave,
+        });
+    }
+}
+
+fn autosave_on_pause(
+    sim_control: Res<SimControl>,
+    mut was_paused: Local<bool>,
+    mut last: ResMut<LastAutosave>,
+    run_stats: Res<RunStats>,
+    player_query: Query<(&Transform, &Health, &Inventory), With<Player>>,
+    mut save_events: EventWriter<SaveGame>,
+) {
+    let paused = sim_control.is_paused();
+    if paused && !*was_paused {
+        last.0 = Some(collect_save_data(&run_stats, &player_query));
+        save_events.send(SaveGame {
+            slot: SaveSlot::Autosave,
+        });
+    }
+    *was_paused = paused;
+}
+
+/// Save right away, since events sent now would never be handled.
+fn autosave_on_exit(
+    mut last: ResMut<LastAutosave>,
+    run_stats: Res<RunStats>,
+    player_query: Query<(&Transform, &Health, &Inventory), With<Player>>,
+) {
+    let data = collect_save_data(&run_stats, &player_query);
+    write_save(SaveSlot::Autosave, &data);
+    last.0 = Some(data);
+}
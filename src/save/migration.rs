@@ -0,0 +1,85 @@
+//! Upgrade saves written by older versions of the game.
+//!
+//! Before a save is deserialized into [`SaveData`], it is parsed into an
+//! untyped [`Value`] and passed through every migration from its version up
+//! to [`SAVE_VERSION`]. When changing the save format, increment
+//! [`SAVE_VERSION`] and add a migration to [`MIGRATIONS`]:
+//!
+//! ```
+//! use bevy::math::Vec2;
+//! use bevy_new_2d::save::{parse_save, SAVE_VERSION};
+//!
+//! let v1 = r#"(
+//!     version: 1,
+//!     level: "levels/level1.ron",
+//!     run_stats: (elapsed: (secs: 90, nanos: 0), score: 3, pickups: 3),
+//!     player: Some((
+//!         position: (10.0, 20.0),
+//!         health: (current: 50.0, max: 100.0),
+//!         inventory: (items: {"coin": 3}, stack_limits: {}),
+//!     )),
+//! )"#;
+//! let save = parse_save(v1).unwrap();
+//! assert_eq!(save.version, SAVE_VERSION);
+//! assert_eq!(save.players.len(), 1);
+//! assert_eq!(save.players[0].position, Vec2::new(10.0, 20.0));
+//! assert_eq!(save.players[0].inventory.count("coin"), 3);
+//! ```
+//!
+//! [`SaveData`]: super::SaveData
+
+use ron::{value::Map, Number, Value};
+
+use super::SAVE_VERSION;
+
+/// Upgrades the fields of a save from one version to the next.
+pub type Migration = fn(&mut Map) -> Result<(), String>;
+
+/// The migration from version `n` to `n + 1` is at index `n - 1`.
+pub const MIGRATIONS: [Migration; SAVE_VERSION as usize - 1] = [migrate_v1_to_v2];
+
+/// Upgrade a parsed save to [`SAVE_VERSION`].
+pub fn migrate(save: Value) -> Result<Value, String> {
+    let Value::Map(mut map) = save else {
+        return Err("the save is not a struct".to_string());
+    };
+    let version = match map.remove(&key("version")) {
+        Some(Value::Number(Number::Integer(version))) => version,
+        _ => return Err("the save has no version".to_string()),
+    };
+    if version < 1 || version > SAVE_VERSION as i64 {
+        return Err(format!(
+            "save version {version} is not supported, the latest is {SAVE_VERSION}"
+        ));
+    }
+
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(version as usize - 1) {
+        let from = index + 1;
+        migration(&mut map).map_err(|error| {
+            format!(
+                "migrating the save from version {from} to {} failed: {error}",
+                from + 1
+            )
+        })?;
+    }
+    map.insert(
+        key("version"),
+        Value::Number(Number::Integer(SAVE_VERSION.into())),
+    );
+    Ok(Value::Map(map))
+}
+
+fn key(name: &str) -> Value {
+    Value::String(name.to_string())
+}
+
+/// Version 2 saves every player instead of a single one.
+fn migrate_v1_to_v2(save: &mut Map) -> Result<(), String> {
+    let players = match save.remove(&key("player")) {
+        Some(Value::Option(None)) => Vec::new(),
+        Some(Value::Option(Some(player))) => vec![*player],
+        _ => return Err("`player` is missing or not an option".to_string()),
+    };
+    save.insert(key("players"), Value::Seq(players));
+    Ok(())
+}
@@ -0,0 +1,310 @@
+//! Save the player's progress to disk and load it again.
+//!
+//! Send [`SaveGame`] to write the current run to a RON file, and [`LoadGame`]
+//! to continue from it. Only a curated set of data is saved, see [`SaveData`].
+//! There are several manual [`SaveSlot`]s, which are listed in [`SaveSlots`].
+//! The run is also saved periodically, see [`autosave`]. For quicksaves of
+//! the entire scene, see [`quicksave`]. Saves can also be shared as text,
+//! see [`share`].
+//!
+//! Saves are stored in [`save_dir`]. There is no file system on web, so saving
+//! and loading are skipped there, see [`SAVING_SUPPORTED`].
+
+pub mod autosave;
+pub mod migration;
+pub mod quicksave;
+pub mod share;
+mod slots;
+
+pub use slots::{DeleteSave, SaveSlotInfo, SaveSlots};
+
+use std::{
+    error::Error,
+    fmt, fs, iter,
+    path::{Path, PathBuf},
+};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    demo::{
+        health::Health, inventory::Inventory, level::LevelAssets, movement::PhysicalTransform,
+        player::Player, run_stats::RunStats,
+    },
+    screens::Screen,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_plugins((autosave::plugin, quicksave::plugin, slots::plugin));
+
+    app.add_event::<SaveGame>();
+    app.add_event::<LoadGame>();
+    app.add_systems(
+        Update,
+        (
+            save_game,
+            load_game,
+            apply_loaded_game
+                .run_if(in_state(Screen::Gameplay).and_then(resource_exists::<LoadedGame>)),
+        ),
+    );
+}
+
+/// Save the current run to disk.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SaveGame {
+    pub slot: SaveSlot,
+}
+
+/// Load a saved run from disk and continue it.
+/// If there is no compatible save, a fresh run is started instead.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LoadGame {
+    pub slot: SaveSlot,
+}
+
+/// The number of manual [`SaveSlot`]s.
+pub const MAX_SAVE_SLOTS: u32 = 5;
+
+/// Which save file to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SaveSlot {
+    /// Saved by the player, numbered from 1 to [`MAX_SAVE_SLOTS`].
+    Manual(u32),
+    /// Saved automatically. Each autosave overwrites the previous one.
+    Autosave,
+}
+
+impl SaveSlot {
+    /// Every slot, starting with the autosave.
+    pub fn all() -> impl Iterator<Item = Self> {
+        iter::once(Self::Autosave).chain((1..=MAX_SAVE_SLOTS).map(Self::Manual))
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    fn file_name(self) -> String {
+        match self {
+            Self::Manual(number) => format!("save_{number}.ron"),
+            Self::Autosave => "autosave.ron".to_string(),
+        }
+    }
+}
+
+impl fmt::Display for SaveSlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Manual(number) => write!(f, "Slot {number}"),
+            Self::Autosave => write!(f, "Autosave"),
+        }
+    }
+}
+
+/// The current version of [`SaveData`].
+/// Increment this whenever its format changes, and add a migration for it to
+/// [`migration::MIGRATIONS`].
+pub const SAVE_VERSION: u32 = 2;
+
+/// The environment variable that overrides [`save_dir`], e.g. for portable
+/// installs that keep their saves next to the executable.
+pub const SAVE_DIR_VAR: &str = "GAME_SAVE_DIR";
+
+/// The directory that saves and settings are stored in, which is created if
+/// it's missing. This is the platform's per-user data directory, e.g.
+/// `~/.local/share/<crate name>` on Linux, unless overridden by [`SAVE_DIR_VAR`].
+/// Falls back to the working directory, with a warning, if neither is available.
+#[cfg(not(target_family = "wasm"))]
+pub fn save_dir() -> PathBuf {
+    let dir = std::env::var_os(SAVE_DIR_VAR)
+        .map(PathBuf::from)
+        .or_else(|| Some(dirs::data_dir()?.join(env!("CARGO_PKG_NAME"))))
+        .unwrap_or_else(|| {
+            warn!(
+                "No data directory found, storing saves in the working directory. \
+                 Set {SAVE_DIR_VAR} to choose a different location."
+            );
+            PathBuf::new()
+        });
+    if let Err(error) = fs::create_dir_all(&dir) {
+        warn!("Failed to create save directory {}: {error}", dir.display());
+    }
+    dir
+}
+
+/// Whether saves can be stored on this platform.
+/// There is no file system on web, so nothing is saved or loaded there.
+pub const SAVING_SUPPORTED: bool = cfg!(not(target_family = "wasm"));
+
+/// Where the save file for `slot` is stored.
+#[cfg(not(target_family = "wasm"))]
+pub fn save_path(slot: SaveSlot) -> Option<PathBuf> {
+    Some(save_dir().join(slot.file_name()))
+}
+
+/// Where the save file for `slot` is stored.
+/// Always `None`, since [`SAVING_SUPPORTED`] is `false` on web.
+#[cfg(target_family = "wasm")]
+pub fn save_path(_slot: SaveSlot) -> Option<PathBuf> {
+    None
+}
+
+/// Everything that is written to the save file.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SaveData {
+    /// See [`SAVE_VERSION`].
+    pub version: u32,
+    /// The path of the level's map.
+    pub level: String,
+    pub run_stats: RunStats,
+    pub players: Vec<PlayerSave>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PlayerSave {
+    pub position: Vec2,
+    pub health: Health,
+    pub inventory: Inventory,
+}
+
+/// Parse a save file, migrating it from older versions if needed.
+pub fn parse_save(contents: &str) -> Result<SaveData, Box<dyn Error + Send + Sync>> {
+    let value = ron::de::from_str(contents)?;
+    let value = migration::migrate(value)?;
+    Ok(value.into_rust()?)
+}
+
+/// Write to a temporary file first and then move it into place, so that a
+/// crash while writing never leaves behind a corrupted file.
+pub fn write_atomic(path: &Path, contents: &str) -> std::io::Result<()> {
+    let temp_path = path.with_extension("ron.tmp");
+    fs::write(&temp_path, contents)?;
+    fs::rename(&temp_path, path)
+}
+
+/// A system that returns the data to save from the current run.
+/// Run it with [`RunSystemOnce`](bevy::ecs::system::RunSystemOnce).
+pub fn current_save_data(
+    run_stats: Res<RunStats>,
+    player_query: Query<(&Transform, &Health, &Inventory), With<Player>>,
+) -> SaveData {
+    collect_save_data(&run_stats, &player_query)
+}
+
+/// Gather the data to save from the current run.
+fn collect_save_data(
+    run_stats: &RunStats,
+    player_query: &Query<(&Transform, &Health, &Inventory), With<Player>>,
+) -> SaveData {
+    let players = player_query
+        .iter()
+        .map(|(transform, health, inventory)| PlayerSave {
+            position: transform.translation.xy(),
+            health: *health,
+            inventory: inventory.clone(),
+        })
+        .collect();
+    SaveData {
+        version: SAVE_VERSION,
+        level: LevelAssets::PATH_MAP.to_string(),
+        run_stats: run_stats.clone(),
+        players,
+    }
+}
+
+/// Write `data` to the save file for `slot`, logging the outcome.
+fn write_save(slot: SaveSlot, data: &SaveData) {
+    let Some(path) = save_path(slot) else {
+        return;
+    };
+    let result = ron::ser::to_string_pretty(data, default())
+        .map_err(|error| error.to_string())
+        .and_then(|contents| write_atomic(&path, &contents).map_err(|error| error.to_string()));
+    match result {
+        Ok(()) => info!("Saved game to {}", path.display()),
+        Err(error) => error!("Failed to save game to {}: {error}", path.display()),
+    }
+}
+
+fn save_game(
+    mut save_events: EventReader<SaveGame>,
+    run_stats: Res<RunStats>,
+    player_query: Query<(&Transform, &Health, &Inventory), With<Player>>,
+    mut save_slots: ResMut<SaveSlots>,
+) {
+    if save_events.is_empty() {
+        return;
+    }
+    let data = collect_save_data(&run_stats, &player_query);
+    for event in save_events.read() {
+        write_save(event.slot, &data);
+    }
+    save_slots.refresh();
+}
+
+/// A loaded save waiting to be applied once the level has spawned.
+/// Insert this and enter [`Screen::Gameplay`] to continue from a save.
+#[derive(Resource, Debug)]
+pub struct LoadedGame(pub SaveData);
+
+fn load_game(
+    mut commands: Commands,
+    mut load_events: EventReader<LoadGame>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    let Some(event) = load_events.read().last() else {
+        return;
+    };
+    if let Some(path) = save_path(event.slot) {
+        match read_save(&path) {
+            Ok(data) => {
+                info!("Loaded game from {}", path.display());
+                commands.insert_resource(LoadedGame(data));
+            }
+            Err(error) => warn!(
+                "Failed to load game from {}, starting fresh: {error}",
+                path.display()
+            ),
+        }
+    }
+    next_screen.set(Screen::Gameplay);
+}
+
+/// Read a save file for the current level.
+fn read_save(path: &Path) -> Result<SaveData, Box<dyn Error + Send + Sync>> {
+    let data = parse_save(&fs::read_to_string(path)?)?;
+    if data.level == LevelAssets::PATH_MAP {
+        Ok(data)
+    } else {
+        Err(format!("unknown level `{}`", data.level).into())
+    }
+}
+
+fn apply_loaded_game(
+    mut commands: Commands,
+    loaded: Res<LoadedGame>,
+    mut run_stats: ResMut<RunStats>,
+    mut player_query: Query<
+        (
+            &mut Transform,
+            &mut Health,
+            &mut Inventory,
+            Option<&mut PhysicalTransform>,
+        ),
+        With<Player>,
+    >,
+) {
+    commands.remove_resource::<LoadedGame>();
+    let data = &loaded.0;
+
+    *run_stats = data.run_stats.clone();
+    for ((mut transform, mut health, mut inventory, physical), player) in
+        player_query.iter_mut().zip(&data.players)
+    {
+        transform.translation = player.position.extend(transform.translation.z);
+        if let Some(mut physical) = physical {
+            *physical = PhysicalTransform::from_translation(transform.translation);
+        }
+        *health = player.health;
+        *inventory = player.inventory.clone();
+    }
+}
@@ -0,0 +1,98 @@
+//! Snapshot the whole gameplay scene to quicksave and quickload it.
+//!
+//! Unlike [`SaveGame`](super::SaveGame), this captures every reflected
+//! component of the entities marked as [`Persistent`], so nothing needs to be
+//! curated. The snapshot is kept in memory for the current session only.
+//!
+//! Press F5 to quicksave and F9 to quickload while playing.
+
+use bevy::{
+    ecs::entity::EntityHashMap, input::common_conditions::input_just_pressed, prelude::*,
+    scene::DynamicSceneBuilder,
+};
+
+use crate::{
+    demo::{level_timer::LevelTimer, run_stats::RunStats},
+    screens::Screen,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<Persistent>();
+    app.init_resource::<Quicksave>();
+    app.add_systems(
+        Update,
+        (
+            quicksave.run_if(input_just_pressed(QUICKSAVE_KEY)),
+            quickload.run_if(input_just_pressed(QUICKLOAD_KEY)),
+        )
+            .run_if(in_state(Screen::Gameplay)),
+    );
+}
+
+const QUICKSAVE_KEY: KeyCode = KeyCode::F5;
+const QUICKLOAD_KEY: KeyCode = KeyCode::F9;
+
+/// Marks an entity to be included in world snapshots.
+/// Only its components that are registered with
+/// [`ReflectComponent`] are captured.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Component)]
+pub struct Persistent;
+
+/// The most recent quicksave.
+#[derive(Resource, Default)]
+struct Quicksave(Option<DynamicScene>);
+
+/// Capture all [`Persistent`] entities and the gameplay resources into a scene.
+pub fn snapshot_world(world: &mut World) -> DynamicScene {
+    let entities = world
+        .query_filtered::<Entity, With<Persistent>>()
+        .iter(world)
+        .collect::<Vec<_>>();
+    DynamicSceneBuilder::from_world(world)
+        .allow_resource::<RunStats>()
+        .allow_resource::<LevelTimer>()
+        .extract_entities(entities.into_iter())
+        .extract_resources()
+        .build()
+}
+
+/// Replace all [`Persistent`] entities with the ones in `snapshot`.
+/// Other entities, such as the camera and UI, are left untouched.
+pub fn restore_world(world: &mut World, snapshot: &DynamicScene) {
+    let entities = world
+        .query_filtered::<Entity, (With<Persistent>, Without<Parent>)>()
+        .iter(world)
+        .collect::<Vec<_>>();
+    for entity in entities {
+        world.entity_mut(entity).despawn_recursive();
+    }
+
+    let mut entity_map = EntityHashMap::default();
+    if let Err(error) = snapshot.write_to_world(world, &mut entity_map) {
+        error!("Failed to restore snapshot: {error}");
+    }
+    // `StateScoped` is not reflected, so it has to be added back.
+    for entity in entity_map.into_values() {
+        world
+            .entity_mut(entity)
+            .insert(StateScoped(Screen::Gameplay));
+    }
+}
+
+fn quicksave(world: &mut World) {
+    let snapshot = snapshot_world(world);
+    info!("Quicksaved {} entities", snapshot.entities.len());
+    world.resource_mut::<Quicksave>().0 = Some(snapshot);
+}
+
+fn quickload(world: &mut World) {
+    world.resource_scope(|world, quicksave: Mut<Quicksave>| {
+        let Some(snapshot) = &quicksave.0 else {
+            warn!("There is no quicksave to load");
+            return;
+        };
+        restore_world(world, snapshot);
+        info!("Quickloaded {} entities", snapshot.entities.len());
+    });
+}
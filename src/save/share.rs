@@ -0,0 +1,31 @@
+//! Turn saves into text that can be copied and pasted, e.g. into bug reports.
+//!
+//! The text is the save's RON, compressed and encoded as base64. This is
+//! especially useful on web, where saves can't be written to disk.
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+use super::{parse_save, SaveData};
+
+/// The largest decompressed save that is accepted, to guard against
+/// strings that decompress into huge amounts of data.
+const MAX_SAVE_SIZE: usize = 1024 * 1024;
+
+/// Encode a save as a shareable string.
+pub fn export_save(data: &SaveData) -> String {
+    let ron = ron::ser::to_string(data).expect("save data only contains serializable types");
+    let compressed = miniz_oxide::deflate::compress_to_vec(ron.as_bytes(), 6);
+    STANDARD.encode(compressed)
+}
+
+/// Decode a string created by [`export_save`].
+/// The error is meant to be shown to the player.
+pub fn import_save(text: &str) -> Result<SaveData, String> {
+    const INVALID: &str = "This is not a save string. Make sure it was copied completely.";
+
+    let compressed = STANDARD.decode(text.trim()).map_err(|_| INVALID)?;
+    let ron = miniz_oxide::inflate::decompress_to_vec_with_limit(&compressed, MAX_SAVE_SIZE)
+        .map_err(|_| INVALID)?;
+    let ron = String::from_utf8(ron).map_err(|_| INVALID)?;
+    parse_save(&ron).map_err(|error| format!("This save can't be loaded: {error}"))
+}
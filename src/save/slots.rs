@@ -0,0 +1,122 @@
+//! List the existing saves, e.g. for a load screen.
+
+use std::{
+    fs,
+    time::{Duration, SystemTime},
+};
+
+use bevy::prelude::*;
+
+use super::{parse_save, save_path, SaveSlot};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_event::<DeleteSave>();
+    app.init_resource::<SaveSlots>();
+    app.add_systems(Startup, load_save_slots);
+    app.add_systems(Update, delete_save);
+}
+
+/// Delete the save file in a slot.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeleteSave {
+    pub slot: SaveSlot,
+}
+
+/// A summary of the save in a slot.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SaveSlotInfo {
+    pub slot: SaveSlot,
+    /// When the save was last written.
+    pub saved_at: SystemTime,
+    /// The path of the level's map.
+    pub level: String,
+    /// The time spent in gameplay during the saved run.
+    pub playtime: Duration,
+    /// Why the save can't be loaded, if it can't.
+    pub error: Option<String>,
+}
+
+/// The slots that contain a save.
+///
+/// This is loaded at startup and refreshed after saving. Call
+/// [`SaveSlots::refresh`] to pick up changes made outside of the game.
+#[derive(Resource, Debug, Default)]
+pub struct SaveSlots(Vec<SaveSlotInfo>);
+
+impl SaveSlots {
+    /// Read the headers of all save files again.
+    pub fn refresh(&mut self) {
+        self.0 = SaveSlot::all().filter_map(read_info).collect();
+    }
+
+    /// All slots that contain a save, starting with the autosave.
+    pub fn iter(&self) -> impl Iterator<Item = &SaveSlotInfo> {
+        self.0.iter()
+    }
+
+    pub fn get(&self, slot: SaveSlot) -> Option<&SaveSlotInfo> {
+        self.0.iter().find(|info| info.slot == slot)
+    }
+
+    /// The slot with the most recent save that can be loaded.
+    pub fn latest(&self) -> Option<SaveSlot> {
+        self.0
+            .iter()
+            .filter(|info| info.error.is_none())
+            .max_by_key(|info| info.saved_at)
+            .map(|info| info.slot)
+    }
+
+    /// The first empty manual slot, or the one with the oldest save if all are full.
+    pub fn next_manual_slot(&self) -> SaveSlot {
+        SaveSlot::all()
+            .filter(|slot| matches!(slot, SaveSlot::Manual(_)))
+            .min_by_key(|&slot| self.get(slot).map(|info| info.saved_at))
+            .unwrap_or(SaveSlot::Manual(1))
+    }
+}
+
+fn load_save_slots(mut save_slots: ResMut<SaveSlots>) {
+    save_slots.refresh();
+}
+
+fn read_info(slot: SaveSlot) -> Option<SaveSlotInfo> {
+    let path = save_path(slot)?;
+    let saved_at = fs::metadata(&path).ok()?.modified().ok()?;
+    let data = fs::read_to_string(&path)
+        .map_err(|error| error.to_string())
+        .and_then(|contents| parse_save(&contents).map_err(|error| error.to_string()));
+
+    Some(match data {
+        Ok(data) => SaveSlotInfo {
+            slot,
+            saved_at,
+            level: data.level,
+            playtime: data.run_stats.elapsed,
+            error: None,
+        },
+        Err(error) => SaveSlotInfo {
+            slot,
+            saved_at,
+            level: String::new(),
+            playtime: Duration::ZERO,
+            error: Some(error),
+        },
+    })
+}
+
+fn delete_save(mut delete_events: EventReader<DeleteSave>, mut save_slots: ResMut<SaveSlots>) {
+    if delete_events.is_empty() {
+        return;
+    }
+    for event in delete_events.read() {
+        let Some(path) = save_path(event.slot) else {
+            continue;
+        };
+        match fs::remove_file(&path) {
+            Ok(()) => info!("Deleted save {}", path.display()),
+            Err(error) => error!("Failed to delete save {}: {error}", path.display()),
+        }
+    }
+    save_slots.refresh();
+}
@@ -3,12 +3,16 @@
 use bevy::{input::common_conditions::input_just_pressed, prelude::*};
 
 use crate::{
-    asset_tracking::LoadResource, audio::Music, demo::level::spawn_level as spawn_level_command,
+    asset_tracking::LoadResource,
+    audio::Music,
+    demo::level::spawn_level as spawn_level_command,
+    save::{SaveGame, SaveSlots, SAVING_SUPPORTED},
     screens::Screen,
+    theme::prelude::*,
 };
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_systems(OnEnter(Screen::Gameplay), spawn_level);
+    app.add_systems(OnEnter(Screen::Gameplay), (spawn_level, spawn_save_button));
 
     app.load_resource::<GameplayMusic>();
     app.add_systems(OnEnter(Screen::Gameplay), play_gameplay_music);
@@ -25,6 +29,39 @@ fn spawn_level(mut commands: Commands) {
     commands.add(spawn_level_command);
 }
 
+fn spawn_save_button(mut commands: Commands) {
+    if !SAVING_SUPPORTED {
+        return;
+    }
+    commands
+        .spawn((
+            Name::new("Save Button Container"),
+            NodeBundle {
+                style: Style {
+                    top: Val::Px(16.0),
+                    right: Val::Px(16.0),
+                    position_type: PositionType::Absolute,
+                    ..default()
+                },
+                ..default()
+            },
+            StateScoped(Screen::Gameplay),
+        ))
+        .with_children(|children| {
+            children.button("Save").observe(save_game);
+        });
+}
+
+fn save_game(
+    _trigger: Trigger<OnPress>,
+    save_slots: Res<SaveSlots>,
+    mut save_events: EventWriter<SaveGame>,
+) {
+    save_events.send(SaveGame {
+        slot: save_slots.next_manual_slot(),
+    });
+}
+
 #[derive(Resource, Asset, Reflect, Clone)]
 pub struct GameplayMusic {
     #[dependency]
@@ -4,7 +4,7 @@
 use bevy::prelude::*;
 
 use crate::{
-    demo::player::PlayerAssets,
+    demo::{level::LevelAssets, pickup::PickupAssets, player::PlayerAssets},
     screens::{credits::CreditsMusic, gameplay::GameplayMusic, Screen},
     theme::{interaction::InteractionAssets, prelude::*},
 };
@@ -34,13 +34,18 @@ fn continue_to_title_screen(mut next_screen: ResMut<NextState<Screen>>) {
     next_screen.set(Screen::Title);
 }
 
-fn all_assets_loaded(
+/// A run condition that is `true` once the assets needed after the loading screen are loaded.
+pub fn all_assets_loaded(
+    level_assets: Option<Res<LevelAssets>>,
+    pickup_assets: Option<Res<PickupAssets>>,
     player_assets: Option<Res<PlayerAssets>>,
     interaction_assets: Option<Res<InteractionAssets>>,
     credits_music: Option<Res<CreditsMusic>>,
     gameplay_music: Option<Res<GameplayMusic>>,
 ) -> bool {
-    player_assets.is_some()
+    level_assets.is_some()
+        && pickup_assets.is_some()
+        && player_assets.is_some()
         && interaction_assets.is_some()
         && credits_music.is_some()
         && gameplay_music.is_some()
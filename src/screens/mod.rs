@@ -3,9 +3,13 @@
 mod credits;
 mod gameplay;
 mod loading;
+mod saves;
+mod settings;
 mod splash;
 mod title;
 
+pub use loading::all_assets_loaded;
+
 use bevy::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
@@ -16,6 +20,8 @@ pub(super) fn plugin(app: &mut App) {
         credits::plugin,
         gameplay::plugin,
         loading::plugin,
+        saves::plugin,
+        settings::plugin,
         splash::plugin,
         title::plugin,
     ));
@@ -29,5 +35,7 @@ pub enum Screen {
     Loading,
     Title,
     Credits,
+    Saves,
+    Settings,
     Gameplay,
 }
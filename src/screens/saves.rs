@@ -0,0 +1,104 @@
+//! A screen listing the saved runs, which can be loaded or deleted from here.
+
+use std::path::Path;
+
+use bevy::prelude::*;
+
+use crate::{
+    save::{DeleteSave, LoadGame, SaveSlotInfo, SaveSlots},
+    screens::Screen,
+    theme::prelude::*,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Saves), refresh_save_slots);
+    // Rebuild the list whenever a save is deleted.
+    app.add_systems(
+        Update,
+        spawn_saves_screen.run_if(in_state(Screen::Saves).and_then(resource_changed::<SaveSlots>)),
+    );
+}
+
+fn refresh_save_slots(mut save_slots: ResMut<SaveSlots>) {
+    save_slots.refresh();
+}
+
+#[derive(Component)]
+struct SavesScreen;
+
+fn spawn_saves_screen(
+    mut commands: Commands,
+    save_slots: Res<SaveSlots>,
+    screen_query: Query<Entity, With<SavesScreen>>,
+) {
+    for entity in &screen_query {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    commands
+        .ui_root()
+        .insert((SavesScreen, StateScoped(Screen::Saves)))
+        .with_children(|children| {
+            children.header("Saves");
+            for info in save_slots.iter() {
+                let slot = info.slot;
+                children
+                    .spawn((
+                        Name::new("Save Slot"),
+                        NodeBundle {
+                            style: Style {
+                                align_items: AlignItems::Center,
+                                column_gap: Val::Px(10.0),
+                                ..default()
+                            },
+                            ..default()
+                        },
+                    ))
+                    .with_children(|children| {
+                        children.label(describe_save(info));
+                        if info.error.is_none() {
+                            children.button("Load").observe(
+                                move |_trigger: Trigger<OnPress>,
+                                      mut load_events: EventWriter<LoadGame>| {
+                                    load_events.send(LoadGame { slot });
+                                },
+                            );
+                        }
+                        children.button("Delete").observe(
+                            move |_trigger: Trigger<OnPress>,
+                                  mut delete_events: EventWriter<DeleteSave>| {
+                                delete_events.send(DeleteSave { slot });
+                            },
+                        );
+                    });
+            }
+            children.button("Back").observe(enter_title_screen);
+        });
+}
+
+/// A line like "Slot 1: level1, 2:35 played, saved 5 min ago".
+fn describe_save(info: &SaveSlotInfo) -> String {
+    if let Some(error) = &info.error {
+        return format!("{}: can't be loaded ({error})", info.slot);
+    }
+    let level = Path::new(&info.level)
+        .file_stem()
+        .map_or(info.level.as_str(), |stem| {
+            stem.to_str().unwrap_or_default()
+        });
+    let playtime = info.playtime.as_secs();
+    let minutes_ago = info
+        .saved_at
+        .elapsed()
+        .map_or(0, |elapsed| elapsed.as_secs() / 60);
+    format!(
+        "{}: {level}, {}:{:02} played, saved {minutes_ago} min ago",
+        info.slot,
+        playtime / 60,
+        playtime % 60,
+    )
+}
+
+fn enter_title_screen(_trigger: Trigger<OnPress>, mut next_screen: ResMut<NextState<Screen>>) {
+    next_screen.set(Screen::Title);
+}
@@ -0,0 +1,95 @@
+//! A screen to change the player's settings.
+
+use bevy::prelude::*;
+
+use crate::{
+    accessibility::AccessibilitySettings, screens::Screen, theme::prelude::*,
+    window::WindowSettings,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Settings), spawn_settings_screen);
+    // Rebuild the screen whenever a setting changes, so it shows the new values.
+    app.add_systems(
+        Update,
+        spawn_settings_screen.run_if(in_state(Screen::Settings).and_then(
+            resource_changed::<AccessibilitySettings>.or_else(resource_changed::<WindowSettings>),
+        )),
+    );
+}
+
+#[derive(Component)]
+struct SettingsScreen;
+
+fn spawn_settings_screen(
+    mut commands: Commands,
+    accessibility: Res<AccessibilitySettings>,
+    window: Res<WindowSettings>,
+    screen_query: Query<Entity, With<SettingsScreen>>,
+) {
+    for entity in &screen_query {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    commands
+        .ui_root()
+        .insert((SettingsScreen, StateScoped(Screen::Settings)))
+        .with_children(|children| {
+            children.header("Settings");
+            children
+                .spawn(setting_row("Reduced Motion Setting"))
+                .with_children(|children| {
+                    children.label("Reduced motion");
+                    children
+                        .button(on_off(accessibility.reduced_motion))
+                        .observe(toggle_reduced_motion);
+                });
+            children
+                .spawn(setting_row("Fullscreen Setting"))
+                .with_children(|children| {
+                    children.label("Fullscreen");
+                    children
+                        .button(on_off(window.fullscreen))
+                        .observe(toggle_fullscreen);
+                });
+            children.button("Back").observe(enter_title_screen);
+        });
+}
+
+/// A row with a setting's label and the button to change it.
+fn setting_row(name: &'static str) -> impl Bundle {
+    (
+        Name::new(name),
+        NodeBundle {
+            style: Style {
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(10.0),
+                ..default()
+            },
+            ..default()
+        },
+    )
+}
+
+fn on_off(enabled: bool) -> &'static str {
+    if enabled {
+        "On"
+    } else {
+        "Off"
+    }
+}
+
+fn toggle_reduced_motion(
+    _trigger: Trigger<OnPress>,
+    mut accessibility: ResMut<AccessibilitySettings>,
+) {
+    accessibility.reduced_motion = !accessibility.reduced_motion;
+}
+
+fn toggle_fullscreen(_trigger: Trigger<OnPress>, mut window: ResMut<WindowSettings>) {
+    window.fullscreen = !window.fullscreen;
+}
+
+fn enter_title_screen(_trigger: Trigger<OnPress>, mut next_screen: ResMut<NextState<Screen>>) {
+    next_screen.set(Screen::Title);
+}
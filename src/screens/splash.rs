@@ -1,28 +1,28 @@
 //! A splash screen that plays briefly at startup.
 
+use std::time::Duration;
+
 use bevy::{
     input::common_conditions::input_just_pressed,
     prelude::*,
     render::texture::{ImageLoaderSettings, ImageSampler},
 };
 
-use crate::{screens::Screen, theme::prelude::*, AppSet};
+use crate::{
+    accessibility::AccessibilitySettings,
+    config::{GameConfig, SplashConfig},
+    easing::Easing,
+    screens::Screen,
+    theme::prelude::*,
+    tween::{Tween, TweenSequence, TweenTarget},
+    AppSet,
+};
 
 pub(super) fn plugin(app: &mut App) {
     // Spawn splash screen.
     app.insert_resource(ClearColor(SPLASH_BACKGROUND_COLOR));
     app.add_systems(OnEnter(Screen::Splash), spawn_splash_screen);
 
-    // Animate splash screen.
-    app.add_systems(
-        Update,
-        (
-            tick_fade_in_out.in_set(AppSet::TickTimers),
-            apply_fade_in_out.in_set(AppSet::Update),
-        )
-            .run_if(in_state(Screen::Splash)),
-    );
-
     // Add splash timer.
     app.register_type::<SplashTimer>();
     app.add_systems(OnEnter(Screen::Splash), insert_splash_timer);
@@ -45,10 +45,13 @@ pub(super) fn plugin(app: &mut App) {
 }
 
 const SPLASH_BACKGROUND_COLOR: Color = Color::srgb(0.157, 0.157, 0.157);
-const SPLASH_DURATION_SECS: f32 = 1.8;
-const SPLASH_FADE_DURATION_SECS: f32 = 0.6;
 
-fn spawn_splash_screen(mut commands: Commands, asset_server: Res<AssetServer>) {
+fn spawn_splash_screen(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    config: Res<GameConfig>,
+    accessibility: Res<AccessibilitySettings>,
+) {
     commands
         .ui_root()
         .insert((
@@ -57,7 +60,7 @@ fn spawn_splash_screen(mut commands: Commands, asset_server: Res<AssetServer>) {
             StateScoped(Screen::Splash),
         ))
         .with_children(|children| {
-            children.spawn((
+            let mut image = children.spawn((
                 Name::new("Splash image"),
                 ImageBundle {
                     style: Style {
@@ -77,61 +80,57 @@ fn spawn_splash_screen(mut commands: Commands, asset_server: Res<AssetServer>) {
                     )),
                     ..default()
                 },
-                UiImageFadeInOut {
-                    total_duration: SPLASH_DURATION_SECS,
-                    fade_duration: SPLASH_FADE_DURATION_SECS,
-                    t: 0.0,
-                },
             ));
+            // With reduced motion, show the image without fading.
+            if !accessibility.reduced_motion {
+                image.insert(fade_in_out(&config.splash));
+            }
         });
 }
 
-#[derive(Component, Reflect)]
-#[reflect(Component)]
-struct UiImageFadeInOut {
-    /// Total duration in seconds.
-    total_duration: f32,
-    /// Fade duration in seconds.
-    fade_duration: f32,
-    /// Current progress in seconds, between 0 and [`Self::total_duration`].
-    t: f32,
-}
-
-impl UiImageFadeInOut {
-    fn alpha(&self) -> f32 {
-        // Normalize by duration.
-        let t = (self.t / self.total_duration).clamp(0.0, 1.0);
-        let fade = self.fade_duration / self.total_duration;
-
-        // Regular trapezoid-shaped graph, flat at the top with alpha = 1.0.
-        ((1.0 - (2.0 * t - 1.0).abs()) / fade).min(1.0)
-    }
-}
-
-fn tick_fade_in_out(time: Res<Time>, mut animation_query: Query<&mut UiImageFadeInOut>) {
-    for mut anim in &mut animation_query {
-        anim.t += time.delta_seconds();
-    }
-}
-
-fn apply_fade_in_out(mut animation_query: Query<(&UiImageFadeInOut, &mut UiImage)>) {
-    for (anim, mut image) in &mut animation_query {
-        image.color.set_alpha(anim.alpha())
-    }
+/// Fade the splash image in, hold it, and fade it out again.
+fn fade_in_out(config: &SplashConfig) -> TweenSequence {
+    let fade = Duration::from_secs_f32(config.fade_duration_secs);
+    let hold = Duration::from_secs_f32(config.duration_secs).saturating_sub(fade * 2);
+    let hidden = Color::WHITE.with_alpha(0.0);
+    let shown = Color::WHITE;
+
+    TweenSequence::new([
+        Tween::new(
+            TweenTarget::Color {
+                from: hidden,
+                to: shown,
+            },
+            fade,
+        )
+        .with_easing(Easing::QuadOut),
+        Tween::new(
+            TweenTarget::Color {
+                from: shown,
+                to: shown,
+            },
+            hold,
+        ),
+        Tween::new(
+            TweenTarget::Color {
+                from: shown,
+                to: hidden,
+            },
+            fade,
+        )
+        .with_easing(Easing::QuadIn),
+    ])
 }
 
 #[derive(Resource, Debug, Clone, PartialEq, Reflect)]
 #[reflect(Resource)]
 struct SplashTimer(Timer);
 
-impl Default for SplashTimer {
-    fn default() -> Self {
-        Self(Timer::from_seconds(SPLASH_DURATION_SECS, TimerMode::Once))
-    }
-}
-
-fn insert_splash_timer(mut commands: Commands) {
-    commands.init_resource::<SplashTimer>();
+fn insert_splash_timer(mut commands: Commands, config: Res<GameConfig>) {
+    commands.insert_resource(SplashTimer(Timer::from_seconds(
+        config.splash.duration_secs,
+        TimerMode::Once,
+    )));
 }
 
 fn remove_splash_timer(mut commands: Commands) {
@@ -2,29 +2,77 @@
 
 use bevy::prelude::*;
 
-use crate::{screens::Screen, theme::prelude::*};
+use crate::{
+    demo::playtime::PlaytimeStats,
+    save::{LoadGame, SaveSlots},
+    screens::Screen,
+    theme::prelude::*,
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::Title), spawn_title_screen);
 }
 
-fn spawn_title_screen(mut commands: Commands) {
+fn spawn_title_screen(
+    mut commands: Commands,
+    save_slots: Res<SaveSlots>,
+    playtime: Res<PlaytimeStats>,
+) {
     commands
         .ui_root()
         .insert(StateScoped(Screen::Title))
         .with_children(|children| {
+            if save_slots.latest().is_some() {
+                children.button("Continue").observe(continue_game);
+            }
             children.button("Play").observe(enter_gameplay_screen);
+            if save_slots.iter().next().is_some() {
+                children.button("Load").observe(enter_saves_screen);
+            }
+            children.button("Settings").observe(enter_settings_screen);
             children.button("Credits").observe(enter_credits_screen);
 
             #[cfg(not(target_family = "wasm"))]
             children.button("Exit").observe(exit_app);
+
+            children.label(describe_playtime(&playtime));
         });
 }
 
+/// A line like "1:05 played over 3 sessions".
+fn describe_playtime(playtime: &PlaytimeStats) -> String {
+    let minutes = playtime.total.as_secs() / 60;
+    format!(
+        "{}:{:02} played over {} sessions",
+        minutes / 60,
+        minutes % 60,
+        playtime.sessions,
+    )
+}
+
 fn enter_gameplay_screen(_trigger: Trigger<OnPress>, mut next_screen: ResMut<NextState<Screen>>) {
     next_screen.set(Screen::Gameplay);
 }
 
+/// Continue from the most recent save, whether it was an autosave or not.
+fn continue_game(
+    _trigger: Trigger<OnPress>,
+    save_slots: Res<SaveSlots>,
+    mut load_events: EventWriter<LoadGame>,
+) {
+    if let Some(slot) = save_slots.latest() {
+        load_events.send(LoadGame { slot });
+    }
+}
+
+fn enter_saves_screen(_trigger: Trigger<OnPress>, mut next_screen: ResMut<NextState<Screen>>) {
+    next_screen.set(Screen::Saves);
+}
+
+fn enter_settings_screen(_trigger: Trigger<OnPress>, mut next_screen: ResMut<NextState<Screen>>) {
+    next_screen.set(Screen::Settings);
+}
+
 fn enter_credits_screen(_trigger: Trigger<OnPress>, mut next_screen: ResMut<NextState<Screen>>) {
     next_screen.set(Screen::Credits);
 }
@@ -0,0 +1,192 @@
+//! Persist player settings across launches.
+//!
+//! All settings are stored in a single `settings.ron`, which is written to the
+//! [`save_dir`](crate::save::save_dir) natively and to `localStorage` on web. Each
+//! feature stores its settings in its own section of the [`SettingsStore`].
+//!
+//! To persist a resource, implement [`Setting`] for it and register it with
+//! [`RegisterSetting::register_setting`]. It is loaded when registered, and
+//! saved whenever it changes.
+
+use std::{collections::BTreeMap, time::Duration};
+
+use bevy::prelude::*;
+use ron::value::RawValue;
+use serde::{de::DeserializeOwned, Serialize};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<SettingsStore>();
+    app.add_systems(
+        Last,
+        (
+            save_settings_after_delay,
+            save_settings_on_exit.run_if(on_event::<AppExit>()),
+        )
+            .chain(),
+    );
+}
+
+/// A resource that is persisted in the [`SettingsStore`].
+pub trait Setting: Resource + Serialize + DeserializeOwned + Default {
+    /// The name of this setting's section in the settings file.
+    const KEY: &'static str;
+}
+
+pub trait RegisterSetting {
+    /// Load a setting from the [`SettingsStore`], falling back to its default,
+    /// and store it again whenever it changes.
+    fn register_setting<T: Setting>(&mut self) -> &mut Self;
+}
+
+impl RegisterSetting for App {
+    fn register_setting<T: Setting>(&mut self) -> &mut Self {
+        let setting = self
+            .world_mut()
+            .get_resource_or_insert_with(SettingsStore::read)
+            .load::<T>(T::KEY)
+            .unwrap_or_default();
+        self.insert_resource(setting);
+        self.add_systems(PostUpdate, store_setting::<T>.run_if(resource_changed::<T>));
+        self
+    }
+}
+
+/// How long to wait after the last change before writing the settings,
+/// so that e.g. dragging a volume slider doesn't write on every frame.
+const SAVE_DELAY: Duration = Duration::from_secs(1);
+
+/// The persisted settings of all features, each in its own section.
+#[derive(Resource, Debug, Default)]
+pub struct SettingsStore {
+    sections: BTreeMap<String, Box<RawValue>>,
+    /// Counts down to writing unsaved changes.
+    pending_save: Option<Timer>,
+}
+
+impl FromWorld for SettingsStore {
+    fn from_world(_world: &mut World) -> Self {
+        Self::read()
+    }
+}
+
+impl SettingsStore {
+    /// Read the settings file. Missing or corrupt files result in an empty store,
+    /// so that every setting falls back to its default.
+    fn read() -> Self {
+        let Some(contents) = backend::read() else {
+            return default();
+        };
+        match ron::de::from_str(&contents) {
+            Ok(sections) => Self {
+                sections,
+                pending_save: None,
+            },
+            Err(error) => {
+                warn!("Ignoring corrupt settings: {error}");
+                default()
+            }
+        }
+    }
+
+    /// The value stored in the section `key`, if there is a valid one.
+    pub fn load<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        let raw = self.sections.get(key)?;
+        raw.into_rust()
+            .map_err(|error| warn!("Ignoring corrupt `{key}` settings: {error}"))
+            .ok()
+    }
+
+    /// Store `value` in the section `key`. The file is written shortly after.
+    pub fn save<T: Serialize>(&mut self, key: &str, value: &T) {
+        let raw = match RawValue::from_rust(value) {
+            Ok(raw) => raw,
+            Err(error) => {
+                error!("Failed to serialize `{key}` settings: {error}");
+                return;
+            }
+        };
+        if self
+            .sections
+            .get(key)
+            .is_some_and(|old| old.get_ron() == raw.get_ron())
+        {
+            return;
+        }
+        self.sections.insert(key.to_string(), raw);
+        self.pending_save = Some(Timer::new(SAVE_DELAY, TimerMode::Once));
+    }
+
+    /// Write the settings file now if there are unsaved changes.
+    pub fn flush(&mut self) {
+        if self.pending_save.take().is_none() {
+            return;
+        }
+        let result = ron::ser::to_string_pretty(&self.sections, default())
+            .map_err(|error| error.to_string())
+            .and_then(|contents| backend::write(&contents));
+        if let Err(error) = result {
+            error!("Failed to save settings: {error}");
+        }
+    }
+}
+
+fn store_setting<T: Setting>(setting: Res<T>, mut store: ResMut<SettingsStore>) {
+    store.save(T::KEY, setting.as_ref());
+}
+
+fn save_settings_after_delay(time: Res<Time<Real>>, mut store: ResMut<SettingsStore>) {
+    let Some(timer) = &mut store.pending_save else {
+        return;
+    };
+    if timer.tick(time.delta()).finished() {
+        store.flush();
+    }
+}
+
+fn save_settings_on_exit(mut store: ResMut<SettingsStore>) {
+    store.flush();
+}
+
+const SETTINGS_FILE_NAME: &str = "settings.ron";
+
+#[cfg(not(target_family = "wasm"))]
+mod backend {
+    use std::{fs, path::PathBuf};
+
+    use super::SETTINGS_FILE_NAME;
+    use crate::save::{save_dir, write_atomic};
+
+    fn path() -> PathBuf {
+        save_dir().join(SETTINGS_FILE_NAME)
+    }
+
+    pub(super) fn read() -> Option<String> {
+        fs::read_to_string(path()).ok()
+    }
+
+    pub(super) fn write(contents: &str) -> Result<(), String> {
+        write_atomic(&path(), contents).map_err(|error| error.to_string())
+    }
+}
+
+#[cfg(target_family = "wasm")]
+mod backend {
+    use web_sys::Storage;
+
+    use super::SETTINGS_FILE_NAME;
+
+    fn local_storage() -> Option<Storage> {
+        web_sys::window()?.local_storage().ok()?
+    }
+
+    pub(super) fn read() -> Option<String> {
+        local_storage()?.get_item(SETTINGS_FILE_NAME).ok()?
+    }
+
+    pub(super) fn write(contents: &str) -> Result<(), String> {
+        local_storage()
+            .ok_or("localStorage is not available")?
+            .set_item(SETTINGS_FILE_NAME, contents)
+            .map_err(|error| format!("{error:?}"))
+    }
+}
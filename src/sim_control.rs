@@ -0,0 +1,101 @@
+//! Programmatic control over whether the simulation advances.
+//!
+//! Unlike the dev tools for pausing time, this is available in all builds, so it
+//! can be used by tests and tooling such as replay recording.
+
+use bevy::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_type::<SimControl>();
+    app.init_resource::<SimControl>();
+    app.add_systems(Last, consume_step);
+}
+
+/// Pause, resume, and step through the systems in the gameplay `AppSet`s,
+/// both in `Update` and `FixedUpdate`.
+///
+/// While paused, no systems in the gameplay sets run except during frames
+/// requested via [`SimControl::step`]:
+///
+/// ```
+/// use bevy::prelude::*;
+/// use bevy_new_2d::sim_control::{consume_step, sim_running, SimControl};
+///
+/// #[derive(Resource, Default)]
+/// struct Frames(u32);
+///
+/// fn count_frames(mut frames: ResMut<Frames>) {
+///     frames.0 += 1;
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<SimControl>();
+/// world.init_resource::<Frames>();
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems((count_frames.run_if(sim_running), consume_step).chain());
+///
+/// // Stepping does nothing unless paused.
+/// world.resource_mut::<SimControl>().step(3);
+/// schedule.run(&mut world);
+/// world.resource_mut::<SimControl>().pause();
+/// for _ in 0..5 {
+///     schedule.run(&mut world);
+/// }
+/// assert_eq!(world.resource::<Frames>().0, 1);
+///
+/// world.resource_mut::<SimControl>().step(3);
+/// for _ in 0..5 {
+///     schedule.run(&mut world);
+/// }
+/// assert_eq!(world.resource::<Frames>().0, 4);
+/// ```
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct SimControl {
+    paused: bool,
+    /// The number of frames left to run while paused.
+    steps: u32,
+}
+
+impl SimControl {
+    /// Stop running the gameplay systems.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Run the gameplay systems every frame again.
+    pub fn resume(&mut self) {
+        self.paused = false;
+        self.steps = 0;
+    }
+
+    /// While paused, run the gameplay systems for the given number of frames.
+    /// Does nothing while running.
+    pub fn step(&mut self, frames: u32) {
+        if self.paused {
+            self.steps += frames;
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Whether the gameplay systems run this frame.
+    pub fn is_running(&self) -> bool {
+        !self.paused || self.steps > 0
+    }
+}
+
+/// A run condition that is `true` on frames where the gameplay systems should run.
+pub fn sim_running(control: Res<SimControl>) -> bool {
+    control.is_running()
+}
+
+/// Use up one of the frames requested via [`SimControl::step`].
+/// This runs at the end of every frame.
+pub fn consume_step(mut control: ResMut<SimControl>) {
+    if control.paused && control.steps > 0 {
+        control.steps -= 1;
+    }
+}
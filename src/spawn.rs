@@ -0,0 +1,74 @@
+//! Helpers for spawning entities with systems.
+//!
+//! Spawning usually needs access to resources like assets, which is easiest
+//! with a system. [`SpawnWith`] runs such a system once, optionally passing it
+//! some data such as a position or a variant:
+//!
+//! ```
+//! use bevy::prelude::*;
+//! use bevy_new_2d::spawn::SpawnWith as _;
+//!
+//! #[derive(Component)]
+//! struct Enemy;
+//!
+//! fn spawn_enemy(In(position): In<Vec2>, mut commands: Commands) {
+//!     commands.spawn((Enemy, Transform::from_translation(position.extend(0.0))));
+//! }
+//!
+//! fn spawn_boss(mut commands: Commands) {
+//!     commands.spawn(Enemy);
+//! }
+//!
+//! let mut world = World::new();
+//! world.spawn_with_data(Vec2::new(64.0, 0.0), spawn_enemy);
+//! world.spawn_with(spawn_boss);
+//! assert_eq!(world.query::<&Enemy>().iter(&world).count(), 2);
+//! ```
+//!
+//! Spawn commands that are used in many places, like
+//! [`SpawnPlayer`](crate::demo::player::SpawnPlayer), are worth wrapping in
+//! their own [`Command`](bevy::ecs::world::Command) instead.
+
+use bevy::{ecs::system::RunSystemOnce as _, prelude::*};
+
+/// An extension trait for spawning with systems.
+/// [`Commands`] run the system as a command, while a [`World`] runs it immediately.
+pub trait SpawnWith {
+    /// Run `system` once.
+    fn spawn_with<M>(&mut self, system: impl IntoSystem<(), (), M> + Send + 'static);
+
+    /// Run `system` once, passing `data` to it as [`In`].
+    fn spawn_with_data<T: Send + 'static, M>(
+        &mut self,
+        data: T,
+        system: impl IntoSystem<T, (), M> + Send + 'static,
+    );
+}
+
+impl SpawnWith for Commands<'_, '_> {
+    fn spawn_with<M>(&mut self, system: impl IntoSystem<(), (), M> + Send + 'static) {
+        self.add(move |world: &mut World| world.spawn_with(system));
+    }
+
+    fn spawn_with_data<T: Send + 'static, M>(
+        &mut self,
+        data: T,
+        system: impl IntoSystem<T, (), M> + Send + 'static,
+    ) {
+        self.add(move |world: &mut World| world.spawn_with_data(data, system));
+    }
+}
+
+impl SpawnWith for World {
+    fn spawn_with<M>(&mut self, system: impl IntoSystem<(), (), M> + Send + 'static) {
+        self.run_system_once(system);
+    }
+
+    fn spawn_with_data<T: Send + 'static, M>(
+        &mut self,
+        data: T,
+        system: impl IntoSystem<T, (), M> + Send + 'static,
+    ) {
+        self.run_system_once_with(data, system);
+    }
+}
@@ -0,0 +1,206 @@
+//! Animate a transform or color from one value to another over time.
+//!
+//! Insert a [`Tween`] to start animating, or a [`TweenSequence`] to play
+//! several tweens one after the other. A [`TweenCompleted`] event is sent
+//! whenever a tween finishes.
+//!
+//! Tweens advance in real time, so UI animations keep playing during a
+//! [`HitStop`](crate::hit_stop::HitStop).
+
+use std::{collections::VecDeque, time::Duration};
+
+use bevy::{color::Mix as _, prelude::*};
+
+use crate::{easing::Easing, AppSet};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_event::<TweenCompleted>();
+    app.add_systems(
+        Update,
+        (
+            start_tween_sequences,
+            advance_tweens,
+            apply_tweens,
+            complete_tweens,
+        )
+            .chain()
+            .in_set(AppSet::Update),
+    );
+}
+
+/// Animates a property of its entity from one value to another.
+#[derive(Component, Debug, Clone)]
+pub struct Tween {
+    pub target: TweenTarget,
+    pub duration: Duration,
+    pub easing: Easing,
+    /// What to do with the entity once the tween is complete.
+    pub on_complete: OnTweenComplete,
+    elapsed: Duration,
+}
+
+impl Tween {
+    pub fn new(target: TweenTarget, duration: Duration) -> Self {
+        Self {
+            target,
+            duration,
+            easing: Easing::Linear,
+            on_complete: OnTweenComplete::Remove,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    pub fn with_easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    pub fn with_on_complete(mut self, on_complete: OnTweenComplete) -> Self {
+        self.on_complete = on_complete;
+        self
+    }
+
+    /// How far along the tween is, from 0 to 1, before easing.
+    pub fn fraction(&self) -> f32 {
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+        (self.elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+/// The property animated by a [`Tween`], and the values to animate between.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TweenTarget {
+    Translation {
+        from: Vec3,
+        to: Vec3,
+    },
+    Scale {
+        from: Vec3,
+        to: Vec3,
+    },
+    /// The rotation around the z axis in radians.
+    Rotation {
+        from: f32,
+        to: f32,
+    },
+    /// The color of a [`Sprite`] or [`UiImage`].
+    Color {
+        from: Color,
+        to: Color,
+    },
+}
+
+/// What happens to the entity when its [`Tween`] completes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OnTweenComplete {
+    /// Remove the [`Tween`], keeping the final value.
+    #[default]
+    Remove,
+    /// Despawn the entity and its children.
+    Despawn,
+}
+
+/// [`Tween`]s that are started one after the other once the current one
+/// completes. The [`OnTweenComplete`] of all but the last are ignored.
+#[derive(Component, Debug, Clone, Default)]
+pub struct TweenSequence(pub VecDeque<Tween>);
+
+impl TweenSequence {
+    pub fn new(tweens: impl IntoIterator<Item = Tween>) -> Self {
+        Self(tweens.into_iter().collect())
+    }
+}
+
+/// Sent when a [`Tween`] completes.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TweenCompleted {
+    pub entity: Entity,
+}
+
+/// Start the first tween of new sequences.
+fn start_tween_sequences(
+    mut commands: Commands,
+    mut sequence_query: Query<(Entity, &mut TweenSequence), Without<Tween>>,
+) {
+    for (entity, mut sequence) in &mut sequence_query {
+        match sequence.0.pop_front() {
+            Some(tween) => commands.entity(entity).insert(tween),
+            None => commands.entity(entity).remove::<TweenSequence>(),
+        };
+    }
+}
+
+fn advance_tweens(time: Res<Time<Real>>, mut tween_query: Query<&mut Tween>) {
+    for mut tween in &mut tween_query {
+        tween.elapsed += time.delta();
+    }
+}
+
+fn apply_tweens(
+    mut tween_query: Query<(
+        &Tween,
+        Option<&mut Transform>,
+        Option<&mut Sprite>,
+        Option<&mut UiImage>,
+    )>,
+) {
+    for (tween, transform, sprite, image) in &mut tween_query {
+        let t = tween.easing.apply(tween.fraction());
+        match tween.target {
+            TweenTarget::Translation { from, to } => {
+                if let Some(mut transform) = transform {
+                    transform.translation = from.lerp(to, t);
+                }
+            }
+            TweenTarget::Scale { from, to } => {
+                if let Some(mut transform) = transform {
+                    transform.scale = from.lerp(to, t);
+                }
+            }
+            TweenTarget::Rotation { from, to } => {
+                if let Some(mut transform) = transform {
+                    transform.rotation = Quat::from_rotation_z(from.lerp(to, t));
+                }
+            }
+            TweenTarget::Color { from, to } => {
+                let color = Color::from(from.to_linear().mix(&to.to_linear(), t));
+                if let Some(mut sprite) = sprite {
+                    sprite.color = color;
+                }
+                if let Some(mut image) = image {
+                    image.color = color;
+                }
+            }
+        }
+    }
+}
+
+fn complete_tweens(
+    mut commands: Commands,
+    mut tween_query: Query<(Entity, &Tween, Option<&mut TweenSequence>)>,
+    mut completed_events: EventWriter<TweenCompleted>,
+) {
+    for (entity, tween, sequence) in &mut tween_query {
+        if !tween.is_complete() {
+            continue;
+        }
+        completed_events.send(TweenCompleted { entity });
+
+        if let Some(next) = sequence.and_then(|mut sequence| sequence.0.pop_front()) {
+            commands.entity(entity).insert(next);
+            continue;
+        }
+        match tween.on_complete {
+            OnTweenComplete::Remove => {
+                commands.entity(entity).remove::<(Tween, TweenSequence)>();
+            }
+            OnTweenComplete::Despawn => commands.entity(entity).despawn_recursive(),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! Remember the primary window's mode and size across launches.
+
+use bevy::{
+    prelude::*,
+    window::{PrimaryWindow, WindowMode, WindowResized},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::settings::{RegisterSetting as _, Setting};
+
+pub(super) fn plugin(app: &mut App) {
+    app.register_setting::<WindowSettings>();
+    app.add_systems(Startup, apply_window_settings);
+    app.add_systems(
+        Update,
+        (
+            record_window_size,
+            apply_window_settings.run_if(resource_changed::<WindowSettings>),
+        )
+            .chain(),
+    );
+}
+
+/// Window settings that are persisted across launches.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct WindowSettings {
+    /// Whether to cover the whole monitor with a borderless window.
+    pub fullscreen: bool,
+    /// The logical size of the window while not fullscreen.
+    /// On web, the canvas is sized to fit the page instead.
+    pub size: Vec2,
+}
+
+impl Default for WindowSettings {
+    fn default() -> Self {
+        Self {
+            fullscreen: false,
+            size: Vec2::new(1280.0, 720.0),
+        }
+    }
+}
+
+impl Setting for WindowSettings {
+    const KEY: &'static str = "window";
+}
+
+fn apply_window_settings(
+    settings: Res<WindowSettings>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    let Ok(mut window) = window_query.get_single_mut() else {
+        return;
+    };
+    let mode = if settings.fullscreen {
+        WindowMode::BorderlessFullscreen
+    } else {
+        WindowMode::Windowed
+    };
+    if window.mode != mode {
+        window.mode = mode;
+    }
+    let size = Vec2::new(window.resolution.width(), window.resolution.height());
+    if !window.fit_canvas_to_parent && size != settings.size {
+        window.resolution.set(settings.size.x, settings.size.y);
+    }
+}
+
+/// Store the size of the window whenever the player resizes it.
+fn record_window_size(
+    mut resize_events: EventReader<WindowResized>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    mut settings: ResMut<WindowSettings>,
+) {
+    let Some(event) = resize_events.read().last() else {
+        return;
+    };
+    let Ok(window) = window_query.get(event.window) else {
+        return;
+    };
+    if window.mode != WindowMode::Windowed || window.fit_canvas_to_parent {
+        return;
+    }
+    let size = Vec2::new(event.width, event.height);
+    if settings.size != size {
+        settings.size = size;
+    }
+}